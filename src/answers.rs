@@ -0,0 +1,198 @@
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Display},
+    fs, io,
+    path::Path,
+    str::FromStr,
+};
+
+use toml::{Table, Value};
+
+use crate::Part;
+
+pub const DEFAULT_ANSWERS_PATH: &str = "answers.toml";
+
+/// The known answers to each day's real input, stored as
+///
+/// ```toml
+/// [day_5]
+/// part_1 = 35
+/// part_2 = 46
+/// ```
+#[derive(Debug, Default, PartialEq)]
+pub struct Answers(BTreeMap<(u8, Part), String>);
+
+#[derive(Debug, PartialEq)]
+pub enum Check<'a> {
+    Pass,
+    Fail { expected: &'a str },
+    Missing,
+}
+
+impl Answers {
+    /// Loads the answers file, a missing file holding no answers yet.
+    pub fn load(path: &Path) -> Result<Self, AnswersError> {
+        match fs::read_to_string(path) {
+            Ok(string) => string.parse(),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Answers::default()),
+            Err(err) => Err(AnswersError::Io(err)),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), AnswersError> {
+        fs::write(path, self.to_string()).map_err(AnswersError::Io)
+    }
+
+    pub fn get(&self, day: u8, part: Part) -> Option<&str> {
+        self.0.get(&(day, part)).map(String::as_str)
+    }
+
+    pub fn record(&mut self, day: u8, part: Part, answer: &str) {
+        self.0.insert((day, part), answer.to_string());
+    }
+
+    pub fn check(&self, day: u8, part: Part, answer: &str) -> Check<'_> {
+        match self.get(day, part) {
+            Some(expected) if expected == answer => Check::Pass,
+            Some(expected) => Check::Fail { expected },
+            None => Check::Missing,
+        }
+    }
+}
+
+impl FromStr for Answers {
+    type Err = AnswersError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let table: Table = s.parse().map_err(AnswersError::Toml)?;
+        let mut answers = Answers::default();
+
+        for (day_key, parts) in table {
+            let day = day_key
+                .strip_prefix("day_")
+                .and_then(|day| day.parse().ok())
+                .ok_or_else(|| {
+                    AnswersError::Invalid(format!("`{}` is not a day_<N> key", day_key))
+                })?;
+            let parts = parts.as_table().ok_or_else(|| {
+                AnswersError::Invalid(format!("`{}` should be a table of parts", day_key))
+            })?;
+
+            for (part_key, answer) in parts {
+                let part = part_key
+                    .strip_prefix("part_")
+                    .and_then(|part| part.parse().ok())
+                    .ok_or_else(|| {
+                        AnswersError::Invalid(format!(
+                            "`{}.{}` is not a part_<1|2> key",
+                            day_key, part_key
+                        ))
+                    })?;
+                let answer = match answer {
+                    Value::Integer(answer) => answer.to_string(),
+                    Value::String(answer) => answer.clone(),
+                    _ => {
+                        return Err(AnswersError::Invalid(format!(
+                            "`{}.{}` should be a number or a string",
+                            day_key, part_key
+                        )))
+                    }
+                };
+                answers.record(day, part, &answer);
+            }
+        }
+
+        Ok(answers)
+    }
+}
+
+impl Display for Answers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut table = Table::new();
+        for ((day, part), answer) in &self.0 {
+            let value = match answer.parse::<i64>() {
+                Ok(answer) => Value::Integer(answer),
+                Err(_) => Value::String(answer.clone()),
+            };
+            table
+                .entry(format!("day_{}", day))
+                .or_insert_with(|| Value::Table(Table::new()))
+                .as_table_mut()
+                .unwrap()
+                .insert(format!("part_{}", part), value);
+        }
+
+        write!(f, "{}", table)
+    }
+}
+
+#[derive(Debug)]
+pub enum AnswersError {
+    Io(io::Error),
+    Toml(toml::de::Error),
+    Invalid(String),
+}
+
+impl Display for AnswersError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AnswersError::Io(err) => write!(f, "cannot access the answers file: {}", err),
+            AnswersError::Toml(err) => write!(f, "the answers file is not valid toml: {}", err),
+            AnswersError::Invalid(reason) => write!(f, "invalid answers file: {}", reason),
+        }
+    }
+}
+
+impl std::error::Error for AnswersError {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn it_parses_answers() {
+        let answers_str = "\
+[day_5]
+part_1 = 35
+part_2 = \"46\"
+
+[day_9]
+part_1 = -114
+";
+        let answers: Answers = answers_str.parse().unwrap();
+
+        assert_eq!(answers.get(5, Part::One), Some("35"));
+        assert_eq!(answers.get(5, Part::Two), Some("46"));
+        assert_eq!(answers.get(9, Part::One), Some("-114"));
+        assert_eq!(answers.get(9, Part::Two), None);
+    }
+
+    #[test]
+    fn it_rejects_unknown_keys() {
+        assert!("[day_5]\npart_3 = 1".parse::<Answers>().is_err());
+        assert!("[five]\npart_1 = 1".parse::<Answers>().is_err());
+    }
+
+    #[test]
+    fn it_checks_answers() {
+        let mut answers = Answers::default();
+        answers.record(5, Part::One, "35");
+
+        assert_eq!(answers.check(5, Part::One, "35"), Check::Pass);
+        assert_eq!(
+            answers.check(5, Part::One, "36"),
+            Check::Fail { expected: "35" }
+        );
+        assert_eq!(answers.check(5, Part::Two, "46"), Check::Missing);
+    }
+
+    #[test]
+    fn it_round_trips_answers() {
+        let mut answers = Answers::default();
+        answers.record(1, Part::One, "142");
+        answers.record(1, Part::Two, "281");
+        answers.record(10, Part::One, "not a number");
+
+        assert_eq!(answers.to_string().parse::<Answers>().unwrap(), answers);
+    }
+}
@@ -0,0 +1,233 @@
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Display},
+    fs,
+    hint::black_box,
+    io,
+    path::Path,
+    time::{Duration, Instant},
+};
+
+use crate::{error::ParseError, Solution};
+
+pub const DEFAULT_BASELINE_PATH: &str = "target/bench_baseline.txt";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Stage {
+    Parse,
+    PartOne,
+    PartTwo,
+}
+
+impl Stage {
+    pub const ALL: [Stage; 3] = [Stage::Parse, Stage::PartOne, Stage::PartTwo];
+
+    fn name(&self) -> &'static str {
+        match self {
+            Stage::Parse => "parse",
+            Stage::PartOne => "part_1",
+            Stage::PartTwo => "part_2",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        Stage::ALL.into_iter().find(|stage| stage.name() == name)
+    }
+}
+
+impl Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self.name())
+    }
+}
+
+/// How long parsing and solving each part of a day took.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Timings {
+    pub parse: Duration,
+    pub part_one: Duration,
+    pub part_two: Option<Duration>,
+}
+
+impl Timings {
+    pub fn get(&self, stage: Stage) -> Option<Duration> {
+        match stage {
+            Stage::Parse => Some(self.parse),
+            Stage::PartOne => Some(self.part_one),
+            Stage::PartTwo => self.part_two,
+        }
+    }
+
+    /// Keeps the fastest time of each stage, the least noisy one.
+    pub fn fastest(self, other: Timings) -> Timings {
+        Timings {
+            parse: self.parse.min(other.parse),
+            part_one: self.part_one.min(other.part_one),
+            part_two: self.part_two.zip(other.part_two).map(|(a, b)| a.min(b)),
+        }
+    }
+}
+
+pub(crate) fn time<S: Solution>(input: &str) -> Result<Timings, ParseError> {
+    let start = Instant::now();
+    let parsed = S::parse(input)?;
+    let parse = start.elapsed();
+
+    let start = Instant::now();
+    black_box(S::part_one(&parsed));
+    let part_one = start.elapsed();
+
+    let part_two = S::HAS_PART_TWO.then(|| {
+        let start = Instant::now();
+        black_box(S::part_two(&parsed));
+        start.elapsed()
+    });
+
+    Ok(Timings {
+        parse,
+        part_one,
+        part_two,
+    })
+}
+
+/// Builds a bigger input of the same format by repeating the records of a day's input.
+/// Returns `None` for days whose input cannot be grown this way.
+pub fn scale_input(day: u8, input: &str, factor: usize) -> Option<String> {
+    match day {
+        // One record per line, duplicated records are still valid.
+        1 | 2 | 3 | 4 | 7 | 9 => {
+            let mut scaled = String::with_capacity((input.len() + 1) * factor);
+            for _ in 0..factor {
+                scaled.push_str(input.trim_end_matches('\n'));
+                scaled.push('\n');
+            }
+            Some(scaled)
+        }
+        // Only the seeds can be repeated, the maps describe a single chain.
+        5 => {
+            let (seeds_line, maps) = input.split_once('\n')?;
+            let seeds = seeds_line.strip_prefix("seeds:")?;
+            Some(format!("seeds:{}\n{}", seeds.repeat(factor), maps))
+        }
+        _ => None,
+    }
+}
+
+/// Timings of a previous run, keyed by day, input label and stage.
+#[derive(Debug, Default, PartialEq)]
+pub struct Baseline(BTreeMap<(u8, String, Stage), Duration>);
+
+impl Baseline {
+    /// Loads a saved baseline, a missing file being an empty baseline.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        let string = match fs::read_to_string(path) {
+            Ok(string) => string,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Baseline::default()),
+            Err(err) => return Err(err),
+        };
+
+        let mut baseline = Baseline::default();
+        for line in string.lines() {
+            let (key, duration) = Self::parse_line(line).ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("invalid baseline line `{}`", line),
+                )
+            })?;
+            baseline.0.insert(key, duration);
+        }
+
+        Ok(baseline)
+    }
+
+    /// A line holds `<day> <input> <stage> <nanoseconds>`.
+    fn parse_line(line: &str) -> Option<((u8, String, Stage), Duration)> {
+        let mut fields = line.split_whitespace();
+        let day = fields.next()?.parse().ok()?;
+        let input = fields.next()?.to_string();
+        let stage = Stage::from_name(fields.next()?)?;
+        let nanos = fields.next()?.parse().ok()?;
+
+        match fields.next() {
+            None => Some(((day, input, stage), Duration::from_nanos(nanos))),
+            Some(_) => None,
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let lines: String = self
+            .0
+            .iter()
+            .map(|((day, input, stage), duration)| {
+                format!("{} {} {} {}\n", day, input, stage, duration.as_nanos())
+            })
+            .collect();
+        fs::write(path, lines)
+    }
+
+    pub fn get(&self, day: u8, input: &str, stage: Stage) -> Option<Duration> {
+        self.0.get(&(day, input.to_string(), stage)).copied()
+    }
+
+    pub fn record(&mut self, day: u8, input: &str, timings: &Timings) {
+        for stage in Stage::ALL {
+            if let Some(duration) = timings.get(stage) {
+                self.0.insert((day, input.to_string(), stage), duration);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn it_scales_line_based_inputs() {
+        let input = "0 3 6\n1 3 6\n";
+        assert_eq!(
+            scale_input(9, input, 2),
+            Some("0 3 6\n1 3 6\n0 3 6\n1 3 6\n".to_string())
+        );
+    }
+
+    #[test]
+    fn it_scales_the_almanac_seeds() {
+        let input = "seeds: 79 14\n\nseed-to-soil map:\n50 98 2";
+        assert_eq!(
+            scale_input(5, input, 3),
+            Some("seeds: 79 14 79 14 79 14\n\nseed-to-soil map:\n50 98 2".to_string())
+        );
+    }
+
+    #[test]
+    fn it_does_not_scale_networks() {
+        assert_eq!(scale_input(8, "LR\n\nAAA = (ZZZ, ZZZ)", 2), None);
+    }
+
+    #[test]
+    fn it_keeps_the_fastest_timings() {
+        let slow = Timings {
+            parse: Duration::from_millis(3),
+            part_one: Duration::from_millis(1),
+            part_two: Some(Duration::from_millis(5)),
+        };
+        let fast = Timings {
+            parse: Duration::from_millis(2),
+            part_one: Duration::from_millis(2),
+            part_two: Some(Duration::from_millis(4)),
+        };
+
+        assert_eq!(
+            slow.fastest(fast),
+            Timings {
+                parse: Duration::from_millis(2),
+                part_one: Duration::from_millis(1),
+                part_two: Some(Duration::from_millis(4)),
+            }
+        );
+    }
+}
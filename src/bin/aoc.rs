@@ -0,0 +1,574 @@
+use std::{
+    panic::{self, UnwindSafe},
+    path::PathBuf,
+    process::ExitCode,
+    time::Duration,
+};
+
+use advent_of_code_2023::{
+    answers::{Answers, Check, DEFAULT_ANSWERS_PATH},
+    bench::{scale_input, Baseline, Stage, Timings, DEFAULT_BASELINE_PATH},
+    day08::{DesertMap, NodePattern, PathOverlay},
+    error::SolveError,
+    generate::{self, Rng},
+    input::{InputError, InputSource},
+    puzzle,
+    report::RunRecord,
+    Part, Puzzle, PUZZLES,
+};
+use clap::{ArgGroup, Args, Parser, Subcommand, ValueEnum};
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Solutions to advent of code 2023")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve one day, or every implemented day
+    Run(RunArgs),
+    /// List the days and parts that are implemented
+    List,
+    /// Check every implemented day's answers against the answers file
+    Verify(VerifyArgs),
+    /// Time the parsing and each part of every day, on real, scaled and generated inputs
+    Bench(BenchArgs),
+    /// Print a generated input for a day
+    Generate(GenerateArgs),
+    /// Inspect the day 8 desert network
+    Desert(DesertArgs),
+}
+
+#[derive(Args)]
+#[command(group(ArgGroup::new("selection").required(true).args(["day", "all"])))]
+struct RunArgs {
+    /// Day to solve
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: Option<u8>,
+
+    /// Part to solve, both parts are solved when omitted
+    #[arg(short, long, requires = "day")]
+    part: Option<Part>,
+
+    /// Solve every implemented day
+    #[arg(short, long)]
+    all: bool,
+
+    /// Input file, `-` to read from stdin. Defaults to `day_<N>.txt` in
+    /// `$AOC_INPUT_DIR`, or in `input/`
+    #[arg(short, long, conflicts_with = "all")]
+    input: Option<PathBuf>,
+
+    /// Refuse inputs that are valid but likely mistaken, like day 5 ranges that map nothing or
+    /// overlap
+    #[arg(long)]
+    strict: bool,
+
+    /// Output format, `json` prints one record per solved part
+    #[arg(short, long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+}
+
+#[derive(Clone, Copy, PartialEq, ValueEnum)]
+enum Format {
+    Text,
+    Json,
+}
+
+#[derive(Args)]
+struct VerifyArgs {
+    /// Answers file, keyed by `[day_<N>]` and `part_<1|2>`
+    #[arg(long, default_value = DEFAULT_ANSWERS_PATH)]
+    answers: PathBuf,
+
+    /// Record the answers missing from the answers file
+    #[arg(short, long)]
+    record: bool,
+}
+
+#[derive(Args)]
+struct BenchArgs {
+    /// Day to benchmark, every implemented day when omitted
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: Option<u8>,
+
+    /// Also benchmark inputs this many times bigger than the real ones, can be repeated
+    #[arg(short, long, value_parser = clap::value_parser!(u64).range(2..))]
+    scale: Vec<u64>,
+
+    /// Also benchmark generated inputs of this many records, can be repeated
+    #[arg(short, long, value_parser = clap::value_parser!(u64).range(1..))]
+    generate: Vec<u64>,
+
+    /// Seed of the generated inputs
+    #[arg(long, default_value_t = 2023)]
+    seed: u64,
+
+    /// Number of runs of each benchmark, the fastest one is kept
+    #[arg(short, long, default_value_t = 3, value_parser = clap::value_parser!(u64).range(1..))]
+    runs: u64,
+
+    /// Baseline the timings are compared against
+    #[arg(long, default_value = DEFAULT_BASELINE_PATH)]
+    baseline: PathBuf,
+
+    /// Save the timings to the baseline
+    #[arg(long)]
+    save: bool,
+}
+
+#[derive(Args)]
+struct GenerateArgs {
+    /// Day whose input format is generated
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+
+    /// Number of records: lines, games, cards, hands, races, seed ranges, rows of the
+    /// schematic or directions of the network
+    #[arg(short, long, default_value_t = 100, value_parser = clap::value_parser!(u64).range(1..))]
+    size: u64,
+
+    /// Seed of the random number generator
+    #[arg(long, default_value_t = 2023)]
+    seed: u64,
+}
+
+#[derive(Args)]
+#[command(group(ArgGroup::new("output").required(true).args(["dot", "steps", "around", "to"])))]
+struct DesertArgs {
+    /// Input file, `-` to read from stdin. Defaults to `day_8.txt` in `$AOC_INPUT_DIR`, or in
+    /// `input/`
+    #[arg(short, long)]
+    input: Option<PathBuf>,
+
+    /// Print the network as a Graphviz DOT graph
+    #[arg(long)]
+    dot: bool,
+
+    /// Colour the path from `AAA` to `ZZZ` in the graph
+    #[arg(long, requires = "dot", conflicts_with = "start")]
+    exit: bool,
+
+    /// Start the walk on this node rather than on `AAA`
+    #[arg(long)]
+    start: Option<String>,
+
+    /// Print the number of steps to a node matching this pattern: `/<regex>/`, `<prefix>*`,
+    /// `*<suffix>`, or names separated by commas
+    #[arg(long)]
+    to: Option<NodePattern>,
+
+    /// Walk ghosts from every node matching this pattern together, rather than a single walk,
+    /// until all of them are on a `--to` node
+    #[arg(long, requires = "to", conflicts_with = "start")]
+    starts: Option<NodePattern>,
+
+    /// Print the first steps of the walk
+    #[arg(long)]
+    steps: Option<usize>,
+
+    /// Print the steps of the walk around this step
+    #[arg(long)]
+    around: Option<usize>,
+
+    /// Number of steps printed before and after the `--around` one
+    #[arg(long, default_value_t = 5, requires = "around")]
+    context: usize,
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    match cli.command {
+        Command::Run(args) => run(&args),
+        Command::List => {
+            list();
+            ExitCode::SUCCESS
+        }
+        Command::Verify(args) => verify(&args),
+        Command::Bench(args) => bench(&args),
+        Command::Generate(args) => {
+            let mut rng = Rng::new(args.seed);
+            match generate::input(args.day, &mut rng, args.size as usize) {
+                Some(input) => {
+                    print!("{}", input);
+                    ExitCode::SUCCESS
+                }
+                None => {
+                    eprintln!("Day {} has no input generator", args.day);
+                    ExitCode::FAILURE
+                }
+            }
+        }
+        Command::Desert(args) => desert(&args),
+    }
+}
+
+fn list() {
+    println!("Day  Part 1  Part 2");
+    for puzzle in PUZZLES.iter() {
+        let status = |part| match puzzle.is_implemented(part) {
+            true => "yes",
+            false => "missing",
+        };
+        println!(
+            "{:>3}  {:<6}  {}",
+            puzzle.day,
+            status(Part::One),
+            status(Part::Two)
+        );
+    }
+}
+
+fn run(args: &RunArgs) -> ExitCode {
+    let puzzles: Vec<&Puzzle> = match args.day {
+        Some(day) => match puzzle(day) {
+            Some(puzzle) => vec![puzzle],
+            None => {
+                eprintln!("Day {} is not solved yet", day);
+                return ExitCode::FAILURE;
+            }
+        },
+        None => PUZZLES.iter().collect(),
+    };
+
+    let mut success = true;
+    let mut records = Vec::new();
+    for puzzle in puzzles {
+        success &= run_puzzle(puzzle, args, &mut records);
+    }
+
+    if args.format == Format::Json {
+        println!("{}", serde_json::to_string(&records).unwrap());
+    }
+
+    match success {
+        true => ExitCode::SUCCESS,
+        false => ExitCode::FAILURE,
+    }
+}
+
+fn run_puzzle(puzzle: &Puzzle, args: &RunArgs, records: &mut Vec<RunRecord>) -> bool {
+    let input = match InputSource::resolve(puzzle.day, args.input.as_deref()).read() {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("Day {}: {}", puzzle.day, err);
+            return false;
+        }
+    };
+
+    let parts = match args.part {
+        Some(part) => vec![part],
+        None => vec![Part::One, Part::Two],
+    };
+
+    let mut success = true;
+    for part in parts {
+        if !puzzle.is_implemented(part) {
+            // Only asking explicitly for a missing part is an error.
+            match args.part {
+                Some(_) => {
+                    eprintln!("Day {} part {} is not implemented", puzzle.day, part);
+                    success = false;
+                }
+                None => note(args.format, puzzle, part, "not implemented"),
+            }
+            continue;
+        }
+
+        match guard(|| puzzle.run(&input, part, args.strict)) {
+            Ok(Some(record)) => {
+                if args.format == Format::Text {
+                    println!("Day {} part {}: {}", puzzle.day, part, record.answer);
+                }
+                records.push(record);
+            }
+            Ok(None) => note(args.format, puzzle, part, "not implemented"),
+            Err(reason) => {
+                eprintln!("Day {} part {} failed: {}", puzzle.day, part, reason);
+                success = false;
+            }
+        }
+    }
+
+    success
+}
+
+/// Prints a remark about a part, out of the way of the records in json.
+fn note(format: Format, puzzle: &Puzzle, part: Part, remark: &str) {
+    match format {
+        Format::Text => println!("Day {} part {}: {}", puzzle.day, part, remark),
+        Format::Json => eprintln!("Day {} part {}: {}", puzzle.day, part, remark),
+    }
+}
+
+/// Turns invalid inputs, missing answers and panicking solvers into a failure reason.
+fn guard<T>(solver: impl FnOnce() -> Result<T, SolveError> + UnwindSafe) -> Result<T, String> {
+    match panic::catch_unwind(solver) {
+        Ok(Ok(result)) => Ok(result),
+        Ok(Err(err)) => Err(err.to_string()),
+        Err(_) => Err("the solver panicked".to_string()),
+    }
+}
+
+fn verify(args: &VerifyArgs) -> ExitCode {
+    let mut answers = match Answers::load(&args.answers) {
+        Ok(answers) => answers,
+        Err(err) => {
+            eprintln!("{}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut success = true;
+    let mut has_new_answers = false;
+    println!("Day  Part  Status    Answer");
+    for puzzle in PUZZLES.iter() {
+        let input = InputSource::resolve(puzzle.day, None).read();
+
+        for part in puzzle.implemented_parts() {
+            let (status, details) = match &input {
+                Err(err) => ("no input", err.to_string()),
+                Ok(input) => match guard(|| puzzle.solve(input, part)) {
+                    Ok(Some(answer)) => match answers.check(puzzle.day, part, &answer) {
+                        Check::Pass => ("pass", answer),
+                        Check::Fail { expected } => {
+                            success = false;
+                            ("FAIL", format!("{} (expected {})", answer, expected))
+                        }
+                        Check::Missing if args.record => {
+                            answers.record(puzzle.day, part, &answer);
+                            has_new_answers = true;
+                            ("recorded", answer)
+                        }
+                        Check::Missing => ("missing", answer),
+                    },
+                    Ok(None) => ("missing", "not implemented".to_string()),
+                    Err(reason) => {
+                        success = false;
+                        ("error", reason)
+                    }
+                },
+            };
+            println!("{:>3}  {:>4}  {:<8}  {}", puzzle.day, part, status, details);
+        }
+    }
+
+    if has_new_answers {
+        if let Err(err) = answers.save(&args.answers) {
+            eprintln!("{}", err);
+            return ExitCode::FAILURE;
+        }
+    }
+
+    match success {
+        true => ExitCode::SUCCESS,
+        false => ExitCode::FAILURE,
+    }
+}
+
+fn bench(args: &BenchArgs) -> ExitCode {
+    let mut baseline = match Baseline::load(&args.baseline) {
+        Ok(baseline) => baseline,
+        Err(err) => {
+            eprintln!("Cannot load the baseline: {}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+    let puzzles: Vec<&Puzzle> = PUZZLES
+        .iter()
+        .filter(|puzzle| args.day.is_none_or(|day| puzzle.day == day))
+        .collect();
+
+    let mut success = true;
+    let mut results: Vec<(u8, String, Timings)> = Vec::new();
+    println!(
+        "Day  Input    {:<22}  {:<22}  {}",
+        Stage::Parse,
+        Stage::PartOne,
+        Stage::PartTwo
+    );
+    for puzzle in puzzles {
+        let inputs = match bench_inputs(puzzle, args) {
+            Ok(inputs) => inputs,
+            Err(err) => {
+                eprintln!("Day {}: {}", puzzle.day, err);
+                success = false;
+                continue;
+            }
+        };
+
+        for (label, input) in inputs {
+            let timings = (0..args.runs).try_fold(None, |fastest: Option<Timings>, _| {
+                let timings = guard(|| puzzle.time(&input).map_err(SolveError::from))?;
+                Ok::<_, String>(Some(fastest.map_or(timings, |f| f.fastest(timings))))
+            });
+            let timings = match timings {
+                Ok(Some(timings)) => timings,
+                Ok(None) => continue,
+                Err(reason) => {
+                    eprintln!("Day {} {} failed: {}", puzzle.day, label, reason);
+                    success = false;
+                    continue;
+                }
+            };
+
+            let cells: Vec<String> = Stage::ALL
+                .into_iter()
+                .map(|stage| match timings.get(stage) {
+                    Some(duration) => {
+                        format_timing(duration, baseline.get(puzzle.day, &label, stage))
+                    }
+                    None => "-".to_string(),
+                })
+                .collect();
+            println!(
+                "{:>3}  {:<7}  {:<22}  {:<22}  {}",
+                puzzle.day, label, cells[0], cells[1], cells[2]
+            );
+            results.push((puzzle.day, label, timings));
+        }
+    }
+
+    if args.save {
+        for (day, label, timings) in &results {
+            baseline.record(*day, label, timings);
+        }
+        if let Err(err) = baseline.save(&args.baseline) {
+            eprintln!("Cannot save the baseline: {}", err);
+            return ExitCode::FAILURE;
+        }
+        println!("Baseline saved to {}", args.baseline.display());
+    }
+
+    match success {
+        true => ExitCode::SUCCESS,
+        false => ExitCode::FAILURE,
+    }
+}
+
+/// The labelled inputs to benchmark a day on: its real input, scaled up copies of it, and
+/// generated ones. The real input is only required when it is scaled.
+fn bench_inputs(puzzle: &Puzzle, args: &BenchArgs) -> Result<Vec<(String, String)>, InputError> {
+    let mut inputs = Vec::new();
+
+    match InputSource::resolve(puzzle.day, None).read() {
+        Ok(input) => {
+            for &scale in &args.scale {
+                if let Some(scaled_input) = scale_input(puzzle.day, &input, scale as usize) {
+                    inputs.push((format!("x{}", scale), scaled_input));
+                }
+            }
+            inputs.insert(0, ("x1".to_string(), input));
+        }
+        Err(err) if args.generate.is_empty() || !args.scale.is_empty() => return Err(err),
+        Err(_) => (),
+    }
+
+    for &size in &args.generate {
+        let mut rng = Rng::new(args.seed);
+        if let Some(generated_input) = generate::input(puzzle.day, &mut rng, size as usize) {
+            inputs.push((format!("gen{}", size), generated_input));
+        }
+    }
+
+    Ok(inputs)
+}
+
+fn desert(args: &DesertArgs) -> ExitCode {
+    let input = match InputSource::resolve(8, args.input.as_deref()).read() {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("Day 8: {}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+    let desert_map = match DesertMap::from_str(&input) {
+        Ok(desert_map) => desert_map,
+        Err(err) => {
+            eprintln!("Day 8: invalid input: {}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    if let Some(exits) = &args.to {
+        let steps = match &args.starts {
+            Some(starts) => desert_map.find_the_exit_of_ghosts(starts, exits),
+            None => desert_map.find_an_exit(args.start.as_deref().unwrap_or("AAA"), exits),
+        };
+        return match steps {
+            Ok(steps) => {
+                println!("{}", steps);
+                ExitCode::SUCCESS
+            }
+            Err(err) => {
+                eprintln!("Day 8: {}", err);
+                ExitCode::FAILURE
+            }
+        };
+    }
+    if !args.dot {
+        return trace_walk(&desert_map, args);
+    }
+
+    let overlay = match (args.exit, &args.start) {
+        (true, _) => Some(PathOverlay::Exit),
+        (_, Some(start)) => Some(PathOverlay::Ghost(start)),
+        _ => None,
+    };
+    match desert_map.to_dot(overlay) {
+        Ok(dot) => {
+            print!("{}", dot);
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("Day 8: {}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Prints the steps of a walk as a table, a walk meeting an unknown node fails.
+fn trace_walk(desert_map: &DesertMap, args: &DesertArgs) -> ExitCode {
+    let start = args.start.as_deref().unwrap_or("AAA");
+    let (first, count) = match (args.steps, args.around) {
+        (Some(steps), _) => (0, steps),
+        (_, Some(around)) => {
+            let first = around.saturating_sub(args.context);
+            (first, around.saturating_add(args.context + 1) - first)
+        }
+        _ => (0, 0),
+    };
+    let Some((&start, _)) = desert_map.network.get_key_value(start) else {
+        eprintln!("Day 8: no start node `{}`", start);
+        return ExitCode::FAILURE;
+    };
+
+    println!("Step  Instruction  Node");
+    for walk_step in desert_map.walk(start).skip(first).take(count) {
+        match walk_step {
+            Ok(walk_step) => println!(
+                "{:>4}  {:>9} {}  {}",
+                walk_step.step, walk_step.direction_index, walk_step.direction, walk_step.node
+            ),
+            Err(err) => {
+                eprintln!("Day 8: {}", err);
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+    ExitCode::SUCCESS
+}
+
+fn format_timing(duration: Duration, baseline: Option<Duration>) -> String {
+    let millis = duration.as_secs_f64() * 1000.0;
+    match baseline {
+        Some(baseline) if !baseline.is_zero() => {
+            let change = (duration.as_secs_f64() / baseline.as_secs_f64() - 1.0) * 100.0;
+            format!("{:.3} ms ({:+.0}%)", millis, change)
+        }
+        _ => format!("{:.3} ms", millis),
+    }
+}
@@ -0,0 +1,132 @@
+use regex::Regex;
+
+use crate::{error::ParseError, Solution};
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input<'a> = &'a str;
+    type Output = usize;
+
+    const HAS_PART_TWO: bool = true;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Ok(input)
+    }
+
+    fn part_one(calibration: &Self::Input<'_>) -> Self::Output {
+        process_digits(calibration)
+    }
+
+    fn part_two(calibration: &Self::Input<'_>) -> Option<Self::Output> {
+        Some(process_digits_and_strings(calibration))
+    }
+}
+
+pub fn process_digits(calibration: &str) -> usize {
+    calibration
+        .lines()
+        .map(|l| {
+            let mut digit_chars = l.chars().filter(|c| c.is_ascii_digit());
+            if let Some(first_e) = digit_chars.next() {
+                let last_e = digit_chars.next_back().unwrap_or(first_e);
+                (first_e.to_string() + &last_e.to_string())
+                    .parse::<usize>()
+                    .unwrap()
+            } else {
+                0
+            }
+        })
+        .sum()
+}
+
+pub fn process_digits_and_strings(calibration: &str) -> usize {
+    let re = Regex::new(r"(\d|one|two|three|four|five|six|seven|eight|nine)").unwrap();
+    // Spelled digits can overlap, as in "oneight": the last one is the first one found in the
+    // reversed line.
+    let re_reversed = Regex::new(r"(\d|eno|owt|eerht|ruof|evif|xis|neves|thgie|enin)").unwrap();
+    calibration
+        .lines()
+        .map(|l| {
+            if let Some(first_m) = re.find(l) {
+                let reversed_l: String = l.chars().rev().collect();
+                let last_m: String = re_reversed
+                    .find(&reversed_l)
+                    .unwrap()
+                    .as_str()
+                    .chars()
+                    .rev()
+                    .collect();
+                (to_digit(first_m.as_str()).to_owned() + to_digit(&last_m))
+                    .parse::<usize>()
+                    .unwrap()
+            } else {
+                0
+            }
+        })
+        .sum()
+}
+
+fn to_digit(m: &str) -> &str {
+    match m {
+        "one" => "1",
+        "two" => "2",
+        "three" => "3",
+        "four" => "4",
+        "five" => "5",
+        "six" => "6",
+        "seven" => "7",
+        "eight" => "8",
+        "nine" => "9",
+        s => s,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn it_processes_digits() {
+        let calibration = "\
+1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet";
+        let result = process_digits(calibration);
+        assert_eq!(result, 142);
+    }
+
+    #[test]
+    fn it_processes_digits_and_strings() {
+        let string = "\
+two1nine
+eightwothree
+abcone2threexyz
+xtwone3four
+4nineeightseven2
+zoneight234
+7pqrstsixteen";
+        let result = process_digits_and_strings(string);
+        assert_eq!(result, 281);
+    }
+    #[test]
+    fn it_processes_digits_and_strings_w_joined_numbers() {
+        let string = "\
+oneight
+twone
+eightwo";
+        let result = process_digits_and_strings(string);
+        assert_eq!(result, 18 + 21 + 82)
+    }
+
+    #[test]
+    fn it_processes_any_overlapping_strings() {
+        let string = "\
+eighthree
+sevenine
+1threeight";
+        let result = process_digits_and_strings(string);
+        assert_eq!(result, 83 + 79 + 18)
+    }
+}
@@ -0,0 +1,241 @@
+use std::fmt::{self, Display};
+
+use regex::Regex;
+
+use crate::{
+    error::{Line, ParseError},
+    Solution,
+};
+
+const DAY: u8 = 2;
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input<'a> = Vec<Game>;
+    type Output = usize;
+
+    const HAS_PART_TWO: bool = true;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_game_records(input)
+    }
+
+    fn part_one(games: &Self::Input<'_>) -> Self::Output {
+        let bag = Bag {
+            red: 12,
+            green: 13,
+            blue: 14,
+        };
+        sum_possible_games(games, &bag)
+    }
+
+    fn part_two(games: &Self::Input<'_>) -> Option<Self::Output> {
+        Some(games.iter().map(|g| g.optimal_bag_power()).sum())
+    }
+}
+
+pub struct Bag {
+    pub red: usize,
+    pub green: usize,
+    pub blue: usize,
+}
+
+#[derive(Debug, Default, PartialEq)]
+pub struct Game {
+    pub id: usize,
+    pub max_red: usize,
+    pub max_green: usize,
+    pub max_blue: usize,
+}
+
+impl Game {
+    pub fn new(id: usize) -> Self {
+        Game {
+            id,
+            ..Default::default()
+        }
+    }
+
+    pub fn is_possible(&self, bag: &Bag) -> bool {
+        self.max_red <= bag.red && self.max_green <= bag.green && self.max_blue <= bag.blue
+    }
+
+    pub fn optimal_bag_power(&self) -> usize {
+        self.max_red * self.max_green * self.max_blue
+    }
+}
+
+impl Display for Game {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Game {}: {} red, {} green, {} blue",
+            self.id, self.max_red, self.max_green, self.max_blue
+        )
+    }
+}
+
+pub fn parse_game_records(records: &str) -> Result<Vec<Game>, ParseError> {
+    let re_game_id = Regex::new(r"^Game (?<id>\d+)$").unwrap();
+    let re_cubes = Regex::new(r"^(?<count>\d+) (?<color>red|green|blue)$").unwrap();
+    records
+        .lines()
+        .enumerate()
+        .map(|(index, l)| {
+            let line = Line::new(DAY, index, l);
+            let (game_def, game_records) = l
+                .split_once(':')
+                .ok_or_else(|| line.error(l, "expected `Game <id>: <cubes>; <cubes>...`"))?;
+            if !re_game_id.is_match(game_def) {
+                return Err(line.error(game_def, "expected `Game <id>`"));
+            }
+            let id = line.parse_number(&game_def["Game ".len()..])?;
+
+            let mut game = Game::new(id);
+            for cubes in game_records.split([';', ',']) {
+                let cubes = cubes.trim();
+                let caps = re_cubes
+                    .captures(cubes)
+                    .ok_or_else(|| line.error(cubes, "expected `<count> <red|green|blue>`"))?;
+                let count = line.parse_number(caps.name("count").unwrap().as_str())?;
+                let max = match &caps["color"] {
+                    "red" => &mut game.max_red,
+                    "green" => &mut game.max_green,
+                    _ => &mut game.max_blue,
+                };
+                if count > *max {
+                    *max = count
+                }
+            }
+            Ok(game)
+        })
+        .collect()
+}
+
+pub fn sum_possible_games(games: &[Game], bag: &Bag) -> usize {
+    games
+        .iter()
+        .filter(|g| g.is_possible(bag))
+        .map(|g| g.id)
+        .sum()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn it_creates_new_game_with_defaults() {
+        assert_eq!(
+            Game::new(1),
+            Game {
+                id: 1,
+                max_red: 0,
+                max_green: 0,
+                max_blue: 0
+            }
+        );
+    }
+
+    #[test]
+    fn it_validates_a_game_is_possible() {
+        let bag = Bag {
+            red: 2,
+            green: 3,
+            blue: 4,
+        };
+        let possible_game = Game {
+            id: 1,
+            max_red: 1,
+            max_green: 2,
+            max_blue: 2,
+        };
+        let impossible_game = Game {
+            id: 2,
+            max_red: 5,
+            max_green: 3,
+            max_blue: 2,
+        };
+        assert!(possible_game.is_possible(&bag));
+        assert!(!impossible_game.is_possible(&bag));
+    }
+
+    #[test]
+    fn it_parses_game_records() {
+        let game_records = "\
+Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green";
+        let result = parse_game_records(game_records).unwrap();
+        assert_eq!(
+            result,
+            vec![Game {
+                id: 1,
+                max_red: 4,
+                max_green: 2,
+                max_blue: 6
+            }]
+        )
+    }
+
+    #[test]
+    fn it_sums_possible_games() {
+        let bag = Bag {
+            red: 12,
+            green: 13,
+            blue: 14,
+        };
+        let games = vec![
+            Game {
+                id: 1,
+                max_red: 4,
+                max_green: 2,
+                max_blue: 6,
+            },
+            Game {
+                id: 2,
+                max_red: 13,
+                max_blue: 5,
+                max_green: 6,
+            },
+            Game {
+                id: 3,
+                max_red: 1,
+                max_blue: 3,
+                max_green: 2,
+            },
+        ];
+        let result = sum_possible_games(&games, &bag);
+        assert_eq!(result, 4)
+    }
+
+    #[test]
+    fn it_parses_and_sums_possible_games() {
+        let bag = Bag {
+            red: 12,
+            green: 13,
+            blue: 14,
+        };
+        let game_records = "\
+Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
+
+        let result = sum_possible_games(&parse_game_records(game_records).unwrap(), &bag);
+        assert_eq!(result, 8);
+    }
+
+    #[test]
+    fn it_reports_invalid_cubes() {
+        let game_records = "\
+Game 1: 3 blue, 4 red
+Game 2: 1 blue, 2 purple";
+
+        let result = parse_game_records(game_records).unwrap_err();
+        assert_eq!(
+            result.to_string(),
+            "day 2, line 2, column 17: expected `<count> <red|green|blue>`, found `2 purple`"
+        );
+    }
+}
@@ -0,0 +1,230 @@
+use regex::Regex;
+
+use crate::{
+    error::{Line, ParseError},
+    Solution,
+};
+
+const DAY: u8 = 3;
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input<'a> = EngineSchematic;
+    type Output = usize;
+
+    const HAS_PART_TWO: bool = true;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_engine_schematics(input)
+    }
+
+    fn part_one(engine_schematic: &Self::Input<'_>) -> Self::Output {
+        sum_part_numbers(engine_schematic)
+    }
+
+    fn part_two(engine_schematic: &Self::Input<'_>) -> Option<Self::Output> {
+        Some(sum_gear_ratios(engine_schematic))
+    }
+}
+
+pub type EngineSchematic = (Vec<Symbol>, Vec<Number>);
+
+#[derive(Debug, PartialEq)]
+pub struct Symbol {
+    pub row: usize,
+    pub col: usize,
+    pub is_potential_gear: bool,
+}
+
+impl Symbol {
+    pub fn new(row: usize, col: usize, is_potential_gear: bool) -> Self {
+        Symbol {
+            row,
+            col,
+            is_potential_gear,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Number {
+    pub row: usize,
+    pub start: usize,
+    pub end: usize,
+    pub value: usize,
+}
+
+impl Number {
+    pub fn new(row: usize, start: usize, end: usize, value: usize) -> Self {
+        Number {
+            row,
+            start,
+            end,
+            value,
+        }
+    }
+
+    pub fn is_part_number(&self, symbol: &Symbol) -> bool {
+        match self.row.abs_diff(symbol.row) {
+            0 => self.start == symbol.col + 1 || self.end + 1 == symbol.col,
+            1 => self.start <= symbol.col + 1 && self.end + 1 >= symbol.col,
+            _ => false,
+        }
+    }
+}
+
+pub fn parse_engine_schematics(schema: &str) -> Result<EngineSchematic, ParseError> {
+    let re = Regex::new(r"(?<number>\d+)|(?<symbol>[^\d\.])").unwrap();
+
+    let mut symbols: Vec<Symbol> = Vec::new();
+    let mut numbers: Vec<Number> = Vec::new();
+
+    for (row, l) in schema.lines().enumerate() {
+        let line = Line::new(DAY, row, l);
+        for cap in re.captures_iter(l) {
+            if let Some(mat) = cap.name("symbol") {
+                let is_potential_gear = matches!(mat.as_str(), "*");
+                symbols.push(Symbol::new(row, mat.start(), is_potential_gear))
+            }
+            if let Some(mat) = cap.name("number") {
+                let value = line.parse_number(mat.as_str())?;
+                numbers.push(Number::new(row, mat.start(), mat.end() - 1, value))
+            }
+        }
+    }
+
+    Ok((symbols, numbers))
+}
+
+/// Draws the schematic back, gears as `*` and any other symbol as `#`.
+pub fn render_engine_schematic((symbols, numbers): &EngineSchematic) -> String {
+    let nb_rows = symbols
+        .iter()
+        .map(|s| s.row + 1)
+        .chain(numbers.iter().map(|n| n.row + 1))
+        .max()
+        .unwrap_or(0);
+    let nb_cols = symbols
+        .iter()
+        .map(|s| s.col + 1)
+        .chain(numbers.iter().map(|n| n.end + 1))
+        .max()
+        .unwrap_or(0);
+
+    let mut grid = vec![vec!['.'; nb_cols]; nb_rows];
+    for symbol in symbols {
+        grid[symbol.row][symbol.col] = if symbol.is_potential_gear { '*' } else { '#' };
+    }
+    for number in numbers {
+        let digits = format!(
+            "{:0width$}",
+            number.value,
+            width = number.end - number.start + 1
+        );
+        for (col, digit) in (number.start..).zip(digits.chars()) {
+            grid[number.row][col] = digit;
+        }
+    }
+
+    grid.into_iter()
+        .map(|row| row.into_iter().collect::<String>() + "\n")
+        .collect()
+}
+
+pub fn sum_part_numbers((symbols, numbers): &EngineSchematic) -> usize {
+    symbols
+        .iter()
+        .map(|s| {
+            numbers
+                .iter()
+                // not super efficient...
+                .filter(|n| n.is_part_number(s))
+                .map(|n| n.value)
+                .sum::<usize>()
+        })
+        .sum()
+}
+
+pub fn sum_gear_ratios((symbols, numbers): &EngineSchematic) -> usize {
+    symbols
+        .iter()
+        .filter(|s| s.is_potential_gear)
+        .map(|s| {
+            numbers
+                .iter()
+                .filter(|n| n.is_part_number(s))
+                .collect::<Vec<&Number>>()
+        })
+        .filter(|potential_gears| potential_gears.len() == 2)
+        .map(|gears| gears[0].value * gears[1].value)
+        .sum()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn it_parses_engine_schematic() {
+        let schema = "\
+467..114#.
+...*......";
+
+        let expected_symbols = vec![Symbol::new(0, 8, false), Symbol::new(1, 3, true)];
+        let expected_numbers = vec![Number::new(0, 0, 2, 467), Number::new(0, 5, 7, 114)];
+
+        let result = parse_engine_schematics(schema).unwrap();
+        assert_eq!(result, (expected_symbols, expected_numbers))
+    }
+
+    #[test]
+    fn it_finds_part_numbers_next_to_the_first_column() {
+        let schema = "\
+#12.
+....
+.3..
+*...";
+        let engine_schematic = parse_engine_schematics(schema).unwrap();
+
+        assert_eq!(sum_part_numbers(&engine_schematic), 15);
+    }
+
+    #[test]
+    fn it_sums_part_numbers() {
+        let schema = "\
+467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..";
+        let engine_schematic = parse_engine_schematics(schema).unwrap();
+
+        let result = sum_part_numbers(&engine_schematic);
+        assert_eq!(result, 4361);
+    }
+
+    #[test]
+    fn it_sums_gear_ratios() {
+        let schema = "\
+467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..";
+        let engine_schematic = parse_engine_schematics(schema).unwrap();
+
+        let result = sum_gear_ratios(&engine_schematic);
+        assert_eq!(result, 467835);
+    }
+}
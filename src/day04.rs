@@ -0,0 +1,252 @@
+use std::fmt::{self, Display};
+
+use regex::Regex;
+
+use crate::{
+    error::{Line, ParseError},
+    Solution,
+};
+
+const DAY: u8 = 4;
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input<'a> = Vec<Card>;
+    type Output = Result<usize, TooManyCopies>;
+
+    const HAS_PART_TWO: bool = true;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_cards(input)
+    }
+
+    fn part_one(cards: &Self::Input<'_>) -> Self::Output {
+        Ok(sum_points(cards))
+    }
+
+    fn part_two(cards: &Self::Input<'_>) -> Option<Self::Output> {
+        Some(count_scratchcards(cards))
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Card {
+    pub winning_numbers: Vec<usize>,
+    pub chosen_numbers: Vec<usize>,
+    /// Copies of the card held, only written by `win_copies`.
+    pub total: usize,
+}
+
+impl Card {
+    pub fn new(winning_numbers: Vec<usize>, chosen_numbers: Vec<usize>) -> Self {
+        Card {
+            winning_numbers,
+            chosen_numbers,
+            total: 1,
+        }
+    }
+
+    pub fn count_matches(&self) -> usize {
+        self.winning_numbers
+            .iter()
+            .filter(|w| self.chosen_numbers.contains(w))
+            .count()
+    }
+
+    pub fn compute_points(&self) -> usize {
+        let nb_correct: u32 = self.count_matches().try_into().unwrap();
+
+        match nb_correct {
+            0 => 0,
+            n => 2_usize.pow(n - 1),
+        }
+    }
+}
+
+impl Display for Card {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Card:")?;
+        for number in &self.winning_numbers {
+            write!(f, " {}", number)?;
+        }
+        write!(f, " |")?;
+        for number in &self.chosen_numbers {
+            write!(f, " {}", number)?;
+        }
+        Ok(())
+    }
+}
+
+pub fn parse_cards(cards: &str) -> Result<Vec<Card>, ParseError> {
+    let re = Regex::new(r"^.*:(?<winnings>((\s+\d+)+)) \|(?<chosen>((\s+\d+)+))$").unwrap();
+    cards
+        .lines()
+        .enumerate()
+        .map(|(index, l)| {
+            let line = Line::new(DAY, index, l);
+            let cap = re.captures(l).ok_or_else(|| {
+                line.error(
+                    l,
+                    "expected `Card <id>: <winning numbers> | <chosen numbers>`",
+                )
+            })?;
+
+            let winnings = cap.name("winnings").unwrap().as_str();
+            let chosen = cap.name("chosen").unwrap().as_str();
+
+            let winnings: Vec<usize> = winnings
+                .split_ascii_whitespace()
+                .map(|n| line.parse_number(n))
+                .collect::<Result<_, _>>()?;
+
+            let chosen: Vec<usize> = chosen
+                .split_ascii_whitespace()
+                .map(|n| line.parse_number(n))
+                .collect::<Result<_, _>>()?;
+
+            Ok(Card::new(winnings, chosen))
+        })
+        .collect()
+}
+
+pub fn sum_points(cards: &[Card]) -> usize {
+    cards.iter().map(|c| c.compute_points()).sum()
+}
+
+/// The copies won, doubling with almost every card, outgrow the largest number.
+#[derive(Debug, PartialEq)]
+pub struct TooManyCopies;
+
+impl Display for TooManyCopies {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "more copies are won than the largest number, {}",
+            usize::MAX
+        )
+    }
+}
+
+impl std::error::Error for TooManyCopies {}
+
+/// How many copies of each card end up won, starting from one of each card.
+/// A card with `n` matches wins one copy of each of the `n` next cards, for each copy of it.
+pub fn count_copies(cards: &[Card]) -> Result<Vec<usize>, TooManyCopies> {
+    let mut copies = vec![1_usize; cards.len()];
+
+    for (index, card) in cards.iter().enumerate() {
+        let last_won = (index + card.count_matches()).min(cards.len() - 1);
+        for won in index + 1..=last_won {
+            copies[won] = copies[won]
+                .checked_add(copies[index])
+                .ok_or(TooManyCopies)?;
+        }
+    }
+
+    Ok(copies)
+}
+
+/// Cascades the copies won by every card into their `total`.
+pub fn win_copies(cards: &mut [Card]) -> Result<(), TooManyCopies> {
+    let copies = count_copies(cards)?;
+    for (card, copies) in cards.iter_mut().zip(copies) {
+        card.total = copies;
+    }
+    Ok(())
+}
+
+pub fn count_scratchcards(cards: &[Card]) -> Result<usize, TooManyCopies> {
+    count_copies(cards)?
+        .into_iter()
+        .try_fold(0_usize, |total, copies| total.checked_add(copies))
+        .ok_or(TooManyCopies)
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn it_parses_cards() {
+        let cards = "\
+Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53";
+
+        let expected = vec![Card::new(
+            vec![41, 48, 83, 86, 17],
+            vec![83, 86, 6, 31, 17, 9, 48, 53],
+        )];
+        let result = parse_cards(cards).unwrap();
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn it_reports_cards_without_chosen_numbers() {
+        let cards = "\
+Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61";
+
+        let result = parse_cards(cards).unwrap_err();
+        assert_eq!((result.line, result.column), (2, 1));
+        assert_eq!(result.text, "Card 2: 13 32 20 16 61");
+    }
+
+    #[test]
+    fn it_compute_card_points() {
+        let card = Card::new(vec![41, 48, 83, 86, 17], vec![83, 86, 6, 31, 17, 9, 48, 53]);
+
+        assert_eq!(card.compute_points(), 8);
+    }
+
+    #[test]
+    fn it_sums_points() {
+        let cards = vec![
+            Card::new(vec![41, 48, 83, 86, 17], vec![83, 86, 6, 31, 17, 9, 48, 53]),
+            Card::new(
+                vec![13, 32, 20, 16, 61],
+                vec![61, 30, 68, 82, 17, 32, 24, 19],
+            ),
+        ];
+
+        assert_eq!(sum_points(&cards), 10)
+    }
+
+    const EXAMPLE: &str = "\
+Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
+
+    #[test]
+    fn it_counts_the_copies_of_each_card() {
+        let cards = parse_cards(EXAMPLE).unwrap();
+
+        assert_eq!(count_copies(&cards), Ok(vec![1, 2, 4, 8, 14, 1]));
+        assert_eq!(count_scratchcards(&cards), Ok(30));
+    }
+
+    #[test]
+    fn it_cascades_copies_into_card_totals() {
+        let mut cards = parse_cards(EXAMPLE).unwrap();
+        win_copies(&mut cards).unwrap();
+
+        let totals: Vec<usize> = cards.iter().map(|c| c.total).collect();
+        assert_eq!(totals, vec![1, 2, 4, 8, 14, 1]);
+
+        // Cascading again gives the same copies.
+        assert_eq!(count_scratchcards(&cards), Ok(30));
+        win_copies(&mut cards).unwrap();
+        let totals: Vec<usize> = cards.iter().map(|c| c.total).collect();
+        assert_eq!(totals, vec![1, 2, 4, 8, 14, 1]);
+    }
+
+    #[test]
+    fn it_does_not_win_cards_past_the_end_of_the_table() {
+        let cards = vec![Card::new(vec![1, 2], vec![1, 2])];
+
+        assert_eq!(count_scratchcards(&cards), Ok(1));
+    }
+}
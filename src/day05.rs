@@ -0,0 +1,1133 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    fmt::{self, Display},
+    ops::Range,
+    str::FromStr,
+};
+
+use crate::{
+    error::{Line, ParseError},
+    Solution,
+};
+
+const DAY: u8 = 5;
+
+/// The categories chained by the almanac maps, in order.
+pub const CATEGORIES: [&str; 8] = [
+    "seed",
+    "soil",
+    "fertilizer",
+    "water",
+    "light",
+    "temperature",
+    "humidity",
+    "location",
+];
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input<'a> = Almanac;
+    type Output = Result<usize, SeedRangeError>;
+
+    const HAS_PART_TWO: bool = true;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        in_order(input, Almanac::from_str(input)?)
+    }
+
+    fn parse_strict(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        in_order(input, Almanac::parse_strict(input)?)
+    }
+
+    fn part_one(almanac: &Self::Input<'_>) -> Self::Output {
+        almanac.get_closest_location()
+    }
+
+    fn part_two(almanac: &Self::Input<'_>) -> Option<Self::Output> {
+        Some(almanac.get_closest_location_of_seed_ranges())
+    }
+}
+
+/// Refuses an almanac read from `input` whose maps do not follow the categories.
+fn in_order(input: &str, almanac: Almanac) -> Result<Almanac, ParseError> {
+    match almanac.check_order() {
+        Err(err @ ConversionError::OutOfOrder { position, .. }) => {
+            let (index, header) = input
+                .lines()
+                .enumerate()
+                .filter(|(_, l)| l.ends_with(" map:"))
+                .nth(position - 1)
+                .unwrap();
+            Err(Line::new(DAY, index, header).error(header, err.to_string()))
+        }
+        _ => Ok(almanac),
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RangeMap {
+    pub destination_start: usize,
+    pub source_start: usize,
+    pub length: usize,
+}
+
+impl RangeMap {
+    pub fn new(destination_start: usize, source_start: usize, length: usize) -> Self {
+        RangeMap {
+            destination_start,
+            source_start,
+            length,
+        }
+    }
+
+    pub fn is_in_range(&self, source: usize) -> bool {
+        source >= self.source_start && source < (self.source_start + self.length)
+    }
+
+    pub fn get_map_destination(&self, source: usize) -> Option<usize> {
+        match self.is_in_range(source) {
+            true => Some(source - self.source_start + self.destination_start),
+            false => None,
+        }
+    }
+
+    pub fn source_range(&self) -> Range<usize> {
+        self.source_start..self.source_start + self.length
+    }
+
+    pub fn destination_range(&self) -> Range<usize> {
+        self.destination_start..self.destination_start + self.length
+    }
+
+    /// Splits `sources` into the part this map covers, mapped to its destinations, and the parts
+    /// before and after it, left unmapped.
+    pub fn split_range(&self, sources: &Range<usize>) -> SplitRange {
+        let covered = self.source_range();
+        let overlap = sources.start.max(covered.start)..sources.end.min(covered.end);
+        if overlap.is_empty() {
+            return SplitRange {
+                mapped: None,
+                unmapped: vec![sources.clone()],
+            };
+        }
+
+        let mapped = overlap.start - self.source_start + self.destination_start
+            ..overlap.end - self.source_start + self.destination_start;
+        let unmapped = [sources.start..overlap.start, overlap.end..sources.end]
+            .into_iter()
+            .filter(|range| !range.is_empty())
+            .collect();
+
+        SplitRange {
+            mapped: Some(mapped),
+            unmapped,
+        }
+    }
+}
+
+/// The outcome of mapping an interval through a single `RangeMap`.
+#[derive(Debug, PartialEq)]
+pub struct SplitRange {
+    pub mapped: Option<Range<usize>>,
+    pub unmapped: Vec<Range<usize>>,
+}
+
+impl Display for RangeMap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {} {}",
+            self.destination_start, self.source_start, self.length
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AlmanacMap {
+    /// The category converted from, as in `<source>-to-<destination> map:`.
+    pub source: String,
+    pub destination: String,
+    pub range_maps: Vec<RangeMap>,
+}
+
+impl AlmanacMap {
+    pub fn new(source: &str, destination: &str, range_maps: Vec<RangeMap>) -> Self {
+        AlmanacMap {
+            source: source.to_string(),
+            destination: destination.to_string(),
+            range_maps,
+        }
+    }
+
+    pub fn get_destination(&self, source: usize) -> usize {
+        let destination_from_map = self
+            .range_maps
+            .iter()
+            .find_map(|r| r.get_map_destination(source));
+
+        match destination_from_map {
+            Some(destination) => destination,
+            None => source,
+        }
+    }
+
+    /// Maps a whole interval of sources, split wherever it crosses a range boundary.
+    /// Sources outside every range keep their number, as in `get_destination`.
+    pub fn get_destination_ranges(&self, sources: Range<usize>) -> Vec<Range<usize>> {
+        self.get_pieces(sources)
+            .iter()
+            .map(RangeMap::destination_range)
+            .collect()
+    }
+
+    /// Cuts `sources` into pieces each sent to its destinations by a single offset, unmapped
+    /// sources giving pieces mapped to themselves.
+    pub fn get_pieces(&self, sources: Range<usize>) -> Vec<RangeMap> {
+        let mut pieces = Vec::new();
+        let mut unmapped = vec![sources];
+
+        for range_map in &self.range_maps {
+            unmapped = unmapped
+                .iter()
+                .flat_map(|sources| {
+                    let split = range_map.split_range(sources);
+                    pieces.extend(split.mapped.map(|destinations| {
+                        RangeMap::new(
+                            destinations.start,
+                            destinations.start - range_map.destination_start
+                                + range_map.source_start,
+                            destinations.len(),
+                        )
+                    }));
+                    split.unmapped
+                })
+                .collect();
+        }
+
+        pieces.extend(
+            unmapped
+                .into_iter()
+                .map(|sources| RangeMap::new(sources.start, sources.start, sources.len())),
+        );
+        pieces
+    }
+
+    /// The map sending each source through `self`, then through `next`, converting from the
+    /// source of `self` to the destination of `next`.
+    pub fn then(&self, next: &AlmanacMap) -> AlmanacMap {
+        let pieces = self
+            .get_pieces(0..usize::MAX)
+            .into_iter()
+            .flat_map(|piece| {
+                next.get_pieces(piece.destination_range())
+                    .into_iter()
+                    .map(move |next_piece| {
+                        RangeMap::new(
+                            next_piece.destination_start,
+                            next_piece.source_start - piece.destination_start + piece.source_start,
+                            next_piece.length,
+                        )
+                    })
+            })
+            .collect();
+
+        AlmanacMap::from_pieces(&self.source, &next.destination, pieces)
+    }
+
+    /// The same map with sorted, non-overlapping ranges, merged where they line up and
+    /// without the ones mapping values to themselves.
+    pub fn normalised(&self) -> AlmanacMap {
+        AlmanacMap::from_pieces(
+            &self.source,
+            &self.destination,
+            self.get_pieces(0..usize::MAX),
+        )
+    }
+
+    fn from_pieces(source: &str, destination: &str, mut pieces: Vec<RangeMap>) -> AlmanacMap {
+        pieces.sort_by_key(|piece| piece.source_start);
+
+        let mut range_maps: Vec<RangeMap> = Vec::new();
+        for piece in pieces {
+            if piece.length == 0 || piece.source_start == piece.destination_start {
+                continue;
+            }
+            match range_maps.last_mut() {
+                Some(last)
+                    if last.source_start + last.length == piece.source_start
+                        && last.destination_start + last.length == piece.destination_start =>
+                {
+                    last.length += piece.length
+                }
+                _ => range_maps.push(piece),
+            }
+        }
+
+        AlmanacMap::new(source, destination, range_maps)
+    }
+
+    /// Every source leading to `destination`, the inverse of `get_destination`.
+    pub fn get_sources(&self, destination: usize) -> Vec<usize> {
+        // No range reaches the largest number, it only comes from itself.
+        let Some(end) = destination.checked_add(1) else {
+            return vec![destination];
+        };
+        let mut sources: Vec<usize> = self
+            .get_source_ranges(destination..end)
+            .into_iter()
+            .flatten()
+            .collect();
+        sources.sort();
+        sources
+    }
+
+    /// Every interval of sources leading into `destinations`, the inverse of
+    /// `get_destination_ranges`.
+    pub fn get_source_ranges(&self, destinations: Range<usize>) -> Vec<Range<usize>> {
+        let mut sources = Vec::new();
+
+        for (index, range_map) in self.range_maps.iter().enumerate() {
+            let covered = range_map.destination_range();
+            let overlap = destinations.start.max(covered.start)..destinations.end.min(covered.end);
+            if overlap.is_empty() {
+                continue;
+            }
+            let candidates = overlap.start - range_map.destination_start + range_map.source_start
+                ..overlap.end - range_map.destination_start + range_map.source_start;
+            // Sources also covered by an earlier range are sent elsewhere by that range.
+            sources.extend(uncovered_ranges(&self.range_maps[..index], candidates));
+        }
+        // Sources outside every range keep their number.
+        sources.extend(uncovered_ranges(&self.range_maps, destinations));
+
+        sources
+    }
+}
+
+/// The parts of `ranges` that none of the `range_maps` cover.
+fn uncovered_ranges(range_maps: &[RangeMap], ranges: Range<usize>) -> Vec<Range<usize>> {
+    range_maps.iter().fold(vec![ranges], |ranges, range_map| {
+        ranges
+            .iter()
+            .flat_map(|range| range_map.split_range(range).unmapped)
+            .collect()
+    })
+}
+
+impl Display for AlmanacMap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for range_map in &self.range_maps {
+            writeln!(f, "{}", range_map)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Almanac {
+    pub seeds: Vec<usize>,
+    pub maps: Vec<AlmanacMap>,
+}
+
+impl Almanac {
+    /// The seeds read as pairs of a start and a length.
+    pub fn seed_ranges(&self) -> Result<Vec<Range<usize>>, SeedRangeError> {
+        let pairs = self.seeds.chunks_exact(2);
+        if let [seed] = pairs.remainder() {
+            return Err(SeedRangeError::Unpaired(*seed));
+        }
+        pairs
+            .map(|pair| match pair[0].checked_add(pair[1]) {
+                Some(end) => Ok(pair[0]..end),
+                None => Err(SeedRangeError::PastTheLargestNumber(pair[0])),
+            })
+            .collect()
+    }
+
+    /// The maps converting seeds to locations, whatever the order they are listed in.
+    fn seed_to_location(&self) -> Result<Vec<&AlmanacMap>, ConversionError> {
+        self.find_path("seed", "location")
+    }
+
+    pub fn get_seed_location(&self, seed: usize) -> Result<usize, ConversionError> {
+        self.convert("seed", "location", seed)
+    }
+
+    pub fn get_closest_location(&self) -> Result<usize, SeedRangeError> {
+        let path = self.seed_to_location()?;
+        self.seeds
+            .iter()
+            .map(|&seed| {
+                path.iter()
+                    .fold(seed, |source, map| map.get_destination(source))
+            })
+            .min()
+            .ok_or(SeedRangeError::NoSeeds)
+    }
+
+    pub fn get_location_ranges(
+        &self,
+        seeds: Range<usize>,
+    ) -> Result<Vec<Range<usize>>, ConversionError> {
+        Ok(self
+            .seed_to_location()?
+            .iter()
+            .fold(vec![seeds], |sources, map| {
+                sources
+                    .into_iter()
+                    .flat_map(|sources| map.get_destination_ranges(sources))
+                    .collect()
+            }))
+    }
+
+    /// Every seed leading to `location`, walking the maps backwards.
+    pub fn get_location_seeds(&self, location: usize) -> Result<Vec<usize>, ConversionError> {
+        let mut seeds =
+            self.seed_to_location()?
+                .iter()
+                .rev()
+                .fold(vec![location], |destinations, map| {
+                    destinations
+                        .into_iter()
+                        .flat_map(|destination| map.get_sources(destination))
+                        .collect()
+                });
+        seeds.sort();
+        Ok(seeds)
+    }
+
+    pub fn get_seed_ranges(
+        &self,
+        locations: Range<usize>,
+    ) -> Result<Vec<Range<usize>>, ConversionError> {
+        Ok(self
+            .seed_to_location()?
+            .iter()
+            .rev()
+            .fold(vec![locations], |destinations, map| {
+                destinations
+                    .into_iter()
+                    .flat_map(|destinations| map.get_source_ranges(destinations))
+                    .collect()
+            }))
+    }
+
+    /// The whole chain of maps as a single seed to location map.
+    pub fn compose_maps(&self) -> Result<AlmanacMap, ConversionError> {
+        self.conversion("seed", "location")
+    }
+
+    /// Checks each map converts from the destination of the previous one, starting from seeds,
+    /// the order the maps of a puzzle almanac are listed in.
+    pub fn check_order(&self) -> Result<(), ConversionError> {
+        let mut expected = "seed";
+        for (index, map) in self.maps.iter().enumerate() {
+            if map.source != expected {
+                return Err(ConversionError::OutOfOrder {
+                    position: index + 1,
+                    expected: expected.to_string(),
+                    found: map.source.clone(),
+                });
+            }
+            expected = &map.destination;
+        }
+        Ok(())
+    }
+
+    /// The maps to follow to convert `source` values to `destination` ones, whatever the order
+    /// they are listed in.
+    pub fn find_path<'a>(
+        &'a self,
+        source: &'a str,
+        destination: &str,
+    ) -> Result<Vec<&'a AlmanacMap>, ConversionError> {
+        for category in [source, destination] {
+            if !self
+                .maps
+                .iter()
+                .any(|map| map.source == category || map.destination == category)
+            {
+                return Err(ConversionError::UnknownCategory(category.to_string()));
+            }
+        }
+
+        // Breadth first, so that the path goes through as few maps as possible.
+        let mut reached_by: HashMap<&str, &AlmanacMap> = HashMap::new();
+        let mut queue = VecDeque::from([source]);
+        while let Some(category) = queue.pop_front() {
+            if category == destination {
+                let mut path = Vec::new();
+                let mut category = destination;
+                while category != source {
+                    let map = reached_by[category];
+                    path.push(map);
+                    category = &map.source;
+                }
+                path.reverse();
+                return Ok(path);
+            }
+
+            for map in self.maps.iter().filter(|map| map.source == category) {
+                if map.destination != source && !reached_by.contains_key(map.destination.as_str()) {
+                    reached_by.insert(&map.destination, map);
+                    queue.push_back(&map.destination);
+                }
+            }
+        }
+
+        Err(ConversionError::NoPath {
+            source: source.to_string(),
+            destination: destination.to_string(),
+        })
+    }
+
+    pub fn convert(
+        &self,
+        source: &str,
+        destination: &str,
+        value: usize,
+    ) -> Result<usize, ConversionError> {
+        let path = self.find_path(source, destination)?;
+        Ok(path
+            .iter()
+            .fold(value, |value, map| map.get_destination(value)))
+    }
+
+    /// A single map converting `source` values to `destination` ones.
+    pub fn conversion(
+        &self,
+        source: &str,
+        destination: &str,
+    ) -> Result<AlmanacMap, ConversionError> {
+        let path = self.find_path(source, destination)?;
+        Ok(path.iter().fold(
+            AlmanacMap::new(source, source, Vec::new()),
+            |composed, map| composed.then(map),
+        ))
+    }
+
+    pub fn get_closest_location_of_seed_ranges(&self) -> Result<usize, SeedRangeError> {
+        let location_ranges = self
+            .seed_ranges()?
+            .into_iter()
+            .filter(|seeds| !seeds.is_empty())
+            .map(|seeds| self.get_location_ranges(seeds))
+            .collect::<Result<Vec<_>, _>>()?;
+        location_ranges
+            .into_iter()
+            .flatten()
+            .map(|locations| locations.start)
+            .min()
+            .ok_or(SeedRangeError::NoSeeds)
+    }
+}
+
+impl Display for Almanac {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "seeds:")?;
+        for seed in &self.seeds {
+            write!(f, " {}", seed)?;
+        }
+        writeln!(f)?;
+
+        for map in &self.maps {
+            write!(f, "\n{}-to-{} map:\n{}", map.source, map.destination, map)?;
+        }
+        Ok(())
+    }
+}
+
+/// The 0-based index and text of the line each range map was read from, map by map.
+type RangeLines<'a> = Vec<Vec<(usize, &'a str)>>;
+
+impl Almanac {
+    /// Parses an almanac, refusing the ones with ranges reported by `diagnose`.
+    pub fn parse_strict(string: &str) -> Result<Almanac, ParseError> {
+        let (almanac, range_lines) = Almanac::parse_with_lines(string)?;
+        match almanac.diagnose_ranges(&range_lines).into_iter().next() {
+            Some(err) => Err(err),
+            None => Ok(almanac),
+        }
+    }
+
+    /// Reports the ranges that map nothing, or overlap an earlier range of the same map, which
+    /// silently takes precedence over them.
+    pub fn diagnose(string: &str) -> Result<Vec<ParseError>, ParseError> {
+        let (almanac, range_lines) = Almanac::parse_with_lines(string)?;
+        Ok(almanac.diagnose_ranges(&range_lines))
+    }
+
+    fn diagnose_ranges(&self, range_lines: &RangeLines<'_>) -> Vec<ParseError> {
+        let mut diagnostics = Vec::new();
+
+        for (map, lines) in self.maps.iter().zip(range_lines) {
+            for (position, (range_map, &(index, l))) in map.range_maps.iter().zip(lines).enumerate()
+            {
+                let line = Line::new(DAY, index, l);
+                if range_map.length == 0 {
+                    diagnostics.push(line.error(l, "empty range, it maps nothing"));
+                    continue;
+                }
+                let overlapped =
+                    map.range_maps[..position]
+                        .iter()
+                        .zip(lines)
+                        .find(|(earlier, _)| {
+                            earlier.length > 0
+                                && earlier.source_start < range_map.source_start + range_map.length
+                                && range_map.source_start
+                                    < earlier.source_start.saturating_add(earlier.length)
+                        });
+                if let Some((_, (earlier_index, _))) = overlapped {
+                    diagnostics.push(line.error(
+                        l,
+                        format!(
+                            "source range overlaps the one on line {}, which takes precedence",
+                            earlier_index + 1
+                        ),
+                    ));
+                }
+            }
+        }
+
+        diagnostics
+    }
+
+    fn parse_with_lines(string: &str) -> Result<(Almanac, RangeLines<'_>), ParseError> {
+        let mut lines = string.lines().enumerate();
+        let (index, seeds_line) = lines
+            .next()
+            .ok_or_else(|| ParseError::new(DAY, 1, 1, "", "expected a `seeds:` line"))?;
+        let line = Line::new(DAY, index, seeds_line);
+        let seeds = seeds_line
+            .strip_prefix("seeds:")
+            .ok_or_else(|| line.error(seeds_line, "expected a `seeds:` line"))?
+            .split_whitespace()
+            .map(|s| line.parse_number(s))
+            .collect::<Result<_, _>>()?;
+
+        let mut maps: Vec<AlmanacMap> = Vec::new();
+        let mut range_lines: RangeLines = Vec::new();
+        let mut current_map: Option<AlmanacMap> = None;
+        for (index, l) in lines {
+            let line = Line::new(DAY, index, l);
+            if l.trim().is_empty() {
+                continue;
+            }
+            if let Some(header) = l.strip_suffix(" map:") {
+                let (source, destination) = header.split_once("-to-").ok_or_else(|| {
+                    line.error(l, "expected a `<source>-to-<destination> map:` header")
+                })?;
+                if let Some(map) =
+                    current_map.replace(AlmanacMap::new(source, destination, Vec::new()))
+                {
+                    maps.push(map);
+                }
+                range_lines.push(Vec::new());
+                continue;
+            }
+
+            let range_params: Vec<usize> = l
+                .split_whitespace()
+                .map(|n| line.parse_number(n))
+                .collect::<Result<_, _>>()?;
+            let range_map = match range_params[..] {
+                [destination_start, source_start, length] => {
+                    RangeMap::new(destination_start, source_start, length)
+                }
+                _ => {
+                    return Err(
+                        line.error(l, "expected `<destination start> <source start> <length>`")
+                    )
+                }
+            };
+            let overflows = [range_map.source_start, range_map.destination_start]
+                .into_iter()
+                .any(|start| start.checked_add(range_map.length).is_none());
+            if overflows {
+                return Err(line.error(
+                    l,
+                    format!("range goes past the largest number, {}", usize::MAX),
+                ));
+            }
+            current_map
+                .as_mut()
+                .ok_or_else(|| line.error(l, "expected a `<source>-to-<destination> map:` header"))?
+                .range_maps
+                .push(range_map);
+            range_lines.last_mut().unwrap().push((index, l));
+        }
+        maps.extend(current_map);
+
+        Ok((Almanac { seeds, maps }, range_lines))
+    }
+}
+
+impl FromStr for Almanac {
+    type Err = ParseError;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        Almanac::parse_with_lines(string).map(|(almanac, _)| almanac)
+    }
+}
+
+/// Why values of a category cannot be converted to another one.
+#[derive(Debug, PartialEq)]
+pub enum ConversionError {
+    UnknownCategory(String),
+    NoPath {
+        source: String,
+        destination: String,
+    },
+    /// `position` is the 1-based position of the first map out of order.
+    OutOfOrder {
+        position: usize,
+        expected: String,
+        found: String,
+    },
+}
+
+impl Display for ConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConversionError::UnknownCategory(category) => {
+                write!(f, "no map converts from or to `{}`", category)
+            }
+            ConversionError::NoPath {
+                source,
+                destination,
+            } => write!(
+                f,
+                "no chain of maps converts `{}` to `{}`",
+                source, destination
+            ),
+            ConversionError::OutOfOrder {
+                position,
+                expected,
+                found,
+            } => write!(
+                f,
+                "map {} converts from `{}` instead of `{}`, maps must be listed in chain order",
+                position, found, expected
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ConversionError {}
+
+/// Why the closest location of the seeds cannot be found.
+#[derive(Debug, PartialEq)]
+pub enum SeedRangeError {
+    /// The last seed has no length.
+    Unpaired(usize),
+    /// The range starting at this seed goes past the largest number.
+    PastTheLargestNumber(usize),
+    /// There are no seeds, or every range of seeds is empty.
+    NoSeeds,
+    /// The maps do not lead from seeds to locations.
+    Unconvertible(ConversionError),
+}
+
+impl From<ConversionError> for SeedRangeError {
+    fn from(err: ConversionError) -> Self {
+        SeedRangeError::Unconvertible(err)
+    }
+}
+
+impl Display for SeedRangeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SeedRangeError::Unpaired(seed) => {
+                write!(f, "seed {} has no length, seeds go in pairs", seed)
+            }
+            SeedRangeError::PastTheLargestNumber(seed) => write!(
+                f,
+                "the range of seeds from {} goes past the largest number, {}",
+                seed,
+                usize::MAX
+            ),
+            SeedRangeError::NoSeeds => write!(f, "there are no seeds to plant"),
+            SeedRangeError::Unconvertible(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for SeedRangeError {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const ALMANAC: &str = "\
+seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4";
+
+    #[test]
+    fn it_finds_the_correct_destination() {
+        let map = AlmanacMap::new(
+            "seed",
+            "soil",
+            vec![RangeMap::new(50, 98, 2), RangeMap::new(52, 50, 48)],
+        );
+
+        assert_eq!(map.get_destination(79), 81);
+        assert_eq!(map.get_destination(14), 14);
+        assert_eq!(map.get_destination(55), 57);
+        assert_eq!(map.get_destination(13), 13);
+    }
+
+    #[test]
+    fn it_finds_the_closest_location() {
+        let almanac = Almanac::from_str(ALMANAC).unwrap();
+        let result = almanac.get_closest_location();
+
+        assert_eq!(result, Ok(35));
+
+        let result = almanac.get_closest_location_of_seed_ranges();
+
+        assert_eq!(result, Ok(46));
+    }
+
+    #[test]
+    fn it_reports_seeds_that_are_not_ranges() {
+        let almanac =
+            Almanac::from_str(&ALMANAC.replace("seeds: 79 14 55 13", "seeds: 79")).unwrap();
+        assert_eq!(
+            almanac.get_closest_location_of_seed_ranges(),
+            Err(SeedRangeError::Unpaired(79))
+        );
+
+        let almanac =
+            Almanac::from_str(&ALMANAC.replace("seeds: 79 14 55 13", "seeds: 79 0")).unwrap();
+        assert_eq!(
+            almanac.get_closest_location_of_seed_ranges(),
+            Err(SeedRangeError::NoSeeds)
+        );
+
+        let almanac = Almanac::from_str(
+            &ALMANAC.replace("seeds: 79 14 55 13", "seeds: 18446744073709551610 10"),
+        )
+        .unwrap();
+        assert_eq!(
+            almanac.get_closest_location_of_seed_ranges(),
+            Err(SeedRangeError::PastTheLargestNumber(18446744073709551610))
+        );
+
+        let input = ALMANAC.replace("seeds: 79 14 55 13", "seeds:");
+        let almanac = Day05::parse(&input).unwrap();
+        assert_eq!(Day05::part_one(&almanac), Err(SeedRangeError::NoSeeds));
+        assert_eq!(
+            Day05::part_two(&almanac),
+            Some(Err(SeedRangeError::NoSeeds))
+        );
+    }
+
+    #[test]
+    fn it_splits_ranges_at_the_map_boundaries() {
+        let range_map = RangeMap::new(50, 98, 2);
+
+        assert_eq!(
+            range_map.split_range(&(90..105)),
+            SplitRange {
+                mapped: Some(50..52),
+                unmapped: vec![90..98, 100..105],
+            }
+        );
+        let before = 10..20;
+        assert_eq!(
+            range_map.split_range(&before),
+            SplitRange {
+                mapped: None,
+                unmapped: vec![before.clone()],
+            }
+        );
+    }
+
+    #[test]
+    fn it_maps_whole_ranges() {
+        let map = AlmanacMap::new(
+            "seed",
+            "soil",
+            vec![RangeMap::new(50, 98, 2), RangeMap::new(52, 50, 48)],
+        );
+
+        let mut destinations = map.get_destination_ranges(40..100);
+        destinations.sort_by_key(|range| range.start);
+
+        assert_eq!(destinations, vec![40..50, 50..52, 52..100]);
+    }
+
+    #[test]
+    fn it_finds_every_source_of_a_destination() {
+        let map = AlmanacMap::new(
+            "seed",
+            "soil",
+            vec![RangeMap::new(50, 98, 2), RangeMap::new(52, 50, 48)],
+        );
+
+        assert_eq!(map.get_sources(81), vec![79]);
+        // 51 comes from 99, while 51 itself is sent to 53.
+        assert_eq!(map.get_sources(51), vec![99]);
+        // Values outside every range map to themselves.
+        assert_eq!(map.get_sources(14), vec![14]);
+        assert_eq!(map.get_sources(99), vec![97]);
+        assert_eq!(map.get_sources(98), vec![96]);
+        assert_eq!(map.get_sources(100), vec![100]);
+        assert_eq!(map.get_sources(usize::MAX), vec![usize::MAX]);
+    }
+
+    #[test]
+    fn it_skips_sources_mapped_by_an_earlier_range() {
+        let map = AlmanacMap::new(
+            "seed",
+            "soil",
+            vec![RangeMap::new(0, 10, 5), RangeMap::new(20, 8, 10)],
+        );
+
+        // 10 would lead to 22 through the second range, but the first one sends it to 0.
+        assert_eq!(map.get_sources(22), vec![22]);
+        assert_eq!(map.get_sources(2), vec![2, 12]);
+        assert_eq!(map.get_source_ranges(20..30), vec![8..10, 15..18, 20..30]);
+    }
+
+    #[test]
+    fn it_composes_maps() {
+        let seed_to_soil = AlmanacMap::new(
+            "seed",
+            "soil",
+            vec![RangeMap::new(50, 98, 2), RangeMap::new(52, 50, 48)],
+        );
+        let soil_to_fertilizer = AlmanacMap::new(
+            "soil",
+            "fertilizer",
+            vec![
+                RangeMap::new(0, 15, 37),
+                RangeMap::new(37, 52, 2),
+                RangeMap::new(39, 0, 15),
+            ],
+        );
+
+        let composed = seed_to_soil.then(&soil_to_fertilizer);
+
+        assert_eq!(
+            composed.to_string(),
+            "\
+39 0 15
+0 15 35
+37 50 2
+54 52 46
+35 98 2
+"
+        );
+        for seed in 0..110 {
+            assert_eq!(
+                composed.get_destination(seed),
+                soil_to_fertilizer.get_destination(seed_to_soil.get_destination(seed))
+            );
+        }
+    }
+
+    #[test]
+    fn it_normalises_maps() {
+        let map = AlmanacMap::new(
+            "seed",
+            "soil",
+            vec![
+                RangeMap::new(20, 10, 5),
+                RangeMap::new(7, 7, 3),
+                RangeMap::new(25, 15, 5),
+                RangeMap::new(0, 12, 10),
+            ],
+        );
+
+        assert_eq!(
+            map.normalised(),
+            AlmanacMap::new(
+                "seed",
+                "soil",
+                vec![RangeMap::new(20, 10, 10), RangeMap::new(8, 20, 2)]
+            )
+        );
+    }
+
+    #[test]
+    fn it_keeps_the_category_names() {
+        let almanac = Almanac::from_str(ALMANAC).unwrap();
+
+        assert_eq!(almanac.maps[1].source, "soil");
+        assert_eq!(almanac.maps[1].destination, "fertilizer");
+        assert_eq!(almanac.to_string().parse::<Almanac>().unwrap(), almanac);
+    }
+
+    #[test]
+    fn it_converts_between_any_categories() {
+        let almanac = Almanac::from_str(ALMANAC).unwrap();
+
+        assert_eq!(almanac.convert("seed", "location", 79), Ok(82));
+        assert_eq!(almanac.convert("humidity", "location", 78), Ok(82));
+        assert_eq!(almanac.convert("soil", "light", 81), Ok(74));
+        assert_eq!(almanac.convert("water", "water", 81), Ok(81));
+        assert_eq!(
+            almanac
+                .conversion("soil", "light")
+                .unwrap()
+                .get_destination(81),
+            74
+        );
+        assert_eq!(
+            almanac.find_path("light", "soil").unwrap_err(),
+            ConversionError::NoPath {
+                source: "light".to_string(),
+                destination: "soil".to_string()
+            }
+        );
+        assert_eq!(
+            almanac.find_path("seed", "dirt").unwrap_err(),
+            ConversionError::UnknownCategory("dirt".to_string())
+        );
+    }
+
+    #[test]
+    fn it_resolves_maps_listed_in_any_order() {
+        let almanac_str = "\
+seeds: 79
+
+soil-to-fertilizer map:
+0 81 1
+
+seed-to-soil map:
+81 79 1";
+        let almanac = Almanac::from_str(almanac_str).unwrap();
+
+        assert_eq!(almanac.convert("seed", "fertilizer", 79), Ok(0));
+        assert_eq!(
+            Day05::parse(almanac_str).unwrap_err().to_string(),
+            "day 5, line 3, column 1: map 1 converts from `soil` instead of `seed`, maps must be listed in chain order, found `soil-to-fertilizer map:`"
+        );
+
+        // The seed to location methods follow the chain of maps too.
+        let almanac_str =
+            almanac_str.replace("0 81 1", "0 81 1\n\nfertilizer-to-location map:\n5 0 1");
+        let almanac = Almanac::from_str(&almanac_str).unwrap();
+
+        assert_eq!(almanac.get_seed_location(79), Ok(5));
+        assert_eq!(almanac.get_closest_location(), Ok(5));
+        assert_eq!(almanac.get_location_seeds(5), Ok(vec![0, 5, 79, 81]));
+        assert_eq!(almanac.get_location_ranges(79..81), Ok(vec![5..6, 80..81]));
+        assert_eq!(
+            almanac.get_seed_ranges(5..6),
+            Ok(vec![79..80, 81..82, 0..1, 5..6])
+        );
+        assert_eq!(almanac.compose_maps().unwrap().get_destination(79), 5);
+
+        let without_locations = Almanac::from_str("seeds: 79\n\nseed-to-soil map:\n81 79 1");
+        assert_eq!(
+            without_locations.unwrap().get_seed_location(79),
+            Err(ConversionError::UnknownCategory("location".to_string()))
+        );
+    }
+
+    #[test]
+    fn it_diagnoses_contradictory_ranges() {
+        let almanac_str = "\
+seeds: 79 14
+
+seed-to-soil map:
+50 98 2
+52 50 48
+0 60 0
+0 97 5";
+
+        let diagnostics: Vec<String> = Almanac::diagnose(almanac_str)
+            .unwrap()
+            .iter()
+            .map(|err| err.to_string())
+            .collect();
+
+        assert_eq!(
+            diagnostics,
+            vec![
+                "day 5, line 6, column 1: empty range, it maps nothing, found `0 60 0`",
+                "day 5, line 7, column 1: source range overlaps the one on line 4, which takes precedence, found `0 97 5`",
+            ]
+        );
+        assert_eq!(Almanac::parse_strict(almanac_str).unwrap_err().line, 6);
+        assert!(Almanac::from_str(almanac_str).is_ok());
+    }
+
+    #[test]
+    fn it_rejects_ranges_past_the_largest_number() {
+        let almanac_str = "\
+seeds: 79 14
+
+seed-to-soil map:
+0 18446744073709551610 10";
+
+        assert_eq!(
+            Almanac::from_str(almanac_str).unwrap_err().to_string(),
+            "day 5, line 4, column 1: range goes past the largest number, 18446744073709551615, found `0 18446744073709551610 10`"
+        );
+        assert!(Almanac::from_str(&almanac_str.replace("610 10", "610 5")).is_ok());
+        assert!(
+            Almanac::from_str(&almanac_str.replace("0 1844", "18446744073709551610 1")).is_err()
+        );
+    }
+
+    #[test]
+    fn it_accepts_valid_almanacs_in_strict_mode() {
+        assert_eq!(Almanac::diagnose(ALMANAC).unwrap(), vec![]);
+        assert_eq!(
+            Almanac::parse_strict(ALMANAC).unwrap(),
+            Almanac::from_str(ALMANAC).unwrap()
+        );
+    }
+
+    #[test]
+    fn it_reports_incomplete_range_maps() {
+        let almanac_str = "\
+seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50";
+
+        let result = Almanac::from_str(almanac_str).unwrap_err();
+        assert_eq!(
+            result.to_string(),
+            "day 5, line 5, column 1: expected `<destination start> <source start> <length>`, found `52 50`"
+        );
+    }
+}
@@ -0,0 +1,334 @@
+use std::fmt::{self, Display};
+
+use num::integer::Roots;
+
+use crate::{
+    error::{Line, ParseError},
+    Solution,
+};
+
+const DAY: u8 = 6;
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input<'a> = RaceSheet;
+    type Output = Result<u64, RaceError>;
+
+    const HAS_PART_TWO: bool = true;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_race_sheet(input)
+    }
+
+    fn part_one((races, _): &Self::Input<'_>) -> Self::Output {
+        races
+            .iter()
+            .map(Race::get_number_of_ways_to_beat_record)
+            .try_fold(1_u64, |product, ways| product.checked_mul(ways))
+            .ok_or(RaceError::TooManyWays)
+    }
+
+    fn part_two((_, kerned_race): &Self::Input<'_>) -> Option<Self::Output> {
+        Some(
+            kerned_race
+                .as_ref()
+                .map(Race::get_number_of_ways_to_beat_record)
+                .map_err(|err| RaceError::Unreadable(err.clone())),
+        )
+    }
+}
+
+/// The races of the sheet, and the single race read when ignoring the spaces between numbers,
+/// which only part 2 needs and may be too large to read.
+pub type RaceSheet = (Vec<Race>, Result<Race, ParseError>);
+
+#[derive(Debug, PartialEq)]
+pub enum RaceError {
+    /// The race read without spaces does not fit in a number.
+    Unreadable(ParseError),
+    /// The product of the ways to win the races outgrows the largest number.
+    TooManyWays,
+}
+
+impl Display for RaceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RaceError::Unreadable(err) => write!(f, "{}", err),
+            RaceError::TooManyWays => write!(
+                f,
+                "the ways to win multiply past the largest number, {}",
+                u64::MAX
+            ),
+        }
+    }
+}
+
+impl std::error::Error for RaceError {}
+
+#[derive(Debug, PartialEq)]
+pub struct Race {
+    pub time: u64,
+    pub record_distance: u64,
+}
+
+impl Race {
+    pub fn new(time: u64, record_distance: u64) -> Self {
+        Race {
+            time,
+            record_distance,
+        }
+    }
+
+    fn beats_record(&self, hold_time: u128) -> bool {
+        hold_time * (self.time as u128 - hold_time) > self.record_distance as u128
+    }
+
+    /// Holding the button `h` ms beats the record when `h * (time - h) > record`, that is for
+    /// every `h` between the roots of `h² - time * h + record + 1`, found with an integer
+    /// square root so that the bounds stay exact whatever the size of the race.
+    pub fn get_number_of_ways_to_beat_record(&self) -> u64 {
+        let time = self.time as u128;
+        let half_time = time / 2;
+        // The distance is the largest when holding half the race, if that loses nothing wins.
+        if !self.beats_record(half_time) {
+            return 0;
+        }
+
+        let discriminant = time * time - 4 * (self.record_distance as u128 + 1);
+        let mut shortest_hold = (time - discriminant.sqrt()) / 2;
+        // The square root is rounded down, the estimate can be off by one either way.
+        while !self.beats_record(shortest_hold) {
+            shortest_hold += 1;
+        }
+        while shortest_hold > 0 && self.beats_record(shortest_hold - 1) {
+            shortest_hold -= 1;
+        }
+
+        // Holding `h` or `time - h` goes as far, winning hold times are symmetric.
+        (time - 2 * shortest_hold + 1) as u64
+    }
+
+    /// Tries every hold time, only usable on short races.
+    pub fn count_ways_by_brute_force(&self) -> u64 {
+        (0..=self.time as u128)
+            .filter(|&hold_time| self.beats_record(hold_time))
+            .count() as u64
+    }
+
+    /// Cross-checks the exact count against brute force on races up to `BRUTE_FORCE_MAX_TIME`,
+    /// longer races only get the exact count.
+    pub fn get_cross_checked_number_of_ways(&self) -> Result<u64, Mismatch> {
+        let exact = self.get_number_of_ways_to_beat_record();
+        if self.time > BRUTE_FORCE_MAX_TIME {
+            return Ok(exact);
+        }
+
+        match self.count_ways_by_brute_force() {
+            brute_force if brute_force == exact => Ok(exact),
+            brute_force => Err(Mismatch { exact, brute_force }),
+        }
+    }
+}
+
+/// Races up to this time are short enough to try every hold time.
+pub const BRUTE_FORCE_MAX_TIME: u64 = 10_000_000;
+
+/// The exact and brute-force counts of a race disagree.
+#[derive(Debug, PartialEq)]
+pub struct Mismatch {
+    pub exact: u64,
+    pub brute_force: u64,
+}
+
+impl Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "the exact count {} differs from the {} found by brute force",
+            self.exact, self.brute_force
+        )
+    }
+}
+
+impl std::error::Error for Mismatch {}
+
+pub fn parse_race_sheet(sheet: &str) -> Result<RaceSheet, ParseError> {
+    let mut lines = sheet.lines().enumerate();
+    let (times, kerned_time) = parse_sheet_line(lines.next(), 0, "Time:")?;
+    let (distances, kerned_distance) = parse_sheet_line(lines.next(), 1, "Distance:")?;
+
+    if times.len() != distances.len() {
+        let (index, l) = sheet.lines().enumerate().nth(1).unwrap();
+        return Err(Line::new(DAY, index, l).error(
+            l,
+            format!(
+                "expected as many distances as times, {} times were found",
+                times.len()
+            ),
+        ));
+    }
+    if let Some((index, l)) = lines.find(|(_, l)| !l.trim().is_empty()) {
+        return Err(Line::new(DAY, index, l).error(l, "expected the end of the race sheet"));
+    }
+
+    let races = times
+        .into_iter()
+        .zip(distances)
+        .map(|(time, record_distance)| Race::new(time, record_distance))
+        .collect();
+
+    let kerned_race = kerned_time
+        .and_then(|time| kerned_distance.map(|record_distance| Race::new(time, record_distance)));
+
+    Ok((races, kerned_race))
+}
+
+/// Reads the numbers of a `<label> <numbers>` line, and the number made of all their digits.
+fn parse_sheet_line(
+    line: Option<(usize, &str)>,
+    expected_index: usize,
+    label: &str,
+) -> Result<(Vec<u64>, Result<u64, ParseError>), ParseError> {
+    let reason = format!("expected a `{}` line", label);
+    let (index, l) =
+        line.ok_or_else(|| ParseError::new(DAY, expected_index + 1, 1, "", reason.as_str()))?;
+    let line = Line::new(DAY, index, l);
+    let numbers_str = l
+        .strip_prefix(label)
+        .ok_or_else(|| line.error(l, reason.as_str()))?;
+
+    let numbers: Vec<u64> = numbers_str
+        .split_whitespace()
+        .map(|n| line.parse_number(n))
+        .collect::<Result<_, _>>()?;
+    if numbers.is_empty() {
+        return Err(line.error(l, "expected at least one race"));
+    }
+
+    let digits: String = numbers_str.split_whitespace().collect();
+    let kerned = digits.parse().map_err(|_| {
+        line.error(
+            numbers_str.trim(),
+            "the number read without spaces is too large",
+        )
+    });
+
+    Ok((numbers, kerned))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn it_finds_the_number_of_ways() {
+        let races = [Race::new(7, 9), Race::new(15, 40), Race::new(30, 200)];
+        let result: Vec<u64> = races
+            .iter()
+            .map(|r| r.get_number_of_ways_to_beat_record())
+            .collect();
+
+        assert_eq!(result, vec![4, 8, 9])
+    }
+
+    #[test]
+    fn it_counts_exactly_on_huge_races() {
+        // The real part 2 race, and races past what an `f64` holds exactly.
+        assert_eq!(
+            Race::new(47986698, 400121310111540).get_number_of_ways_to_beat_record(),
+            26499773
+        );
+        assert_eq!(
+            Race::new(u64::MAX, 0).get_number_of_ways_to_beat_record(),
+            u64::MAX - 1
+        );
+        assert_eq!(
+            Race::new(u64::MAX, u64::MAX).get_number_of_ways_to_beat_record(),
+            u64::MAX - 3
+        );
+        let time = 1 << 31;
+        let best_distance = (time / 2) * (time / 2);
+        assert_eq!(
+            Race::new(time, best_distance - 1).get_number_of_ways_to_beat_record(),
+            1
+        );
+        assert_eq!(
+            Race::new(time, best_distance).get_number_of_ways_to_beat_record(),
+            0
+        );
+    }
+
+    #[test]
+    fn it_cross_checks_short_races() {
+        let races = [
+            Race::new(7, 9),
+            Race::new(15, 40),
+            Race::new(30, 200),
+            Race::new(0, 0),
+        ];
+        let result: Vec<Result<u64, Mismatch>> = races
+            .iter()
+            .map(|r| r.get_cross_checked_number_of_ways())
+            .collect();
+
+        assert_eq!(result, vec![Ok(4), Ok(8), Ok(9), Ok(0)]);
+    }
+
+    #[test]
+    fn it_parses_a_race_sheet() {
+        let sheet = "\
+Time:      7  15   30
+Distance:  9  40  200";
+
+        let (races, kerned_race) = parse_race_sheet(sheet).unwrap();
+
+        assert_eq!(
+            races,
+            vec![Race::new(7, 9), Race::new(15, 40), Race::new(30, 200)]
+        );
+        assert_eq!(kerned_race, Ok(Race::new(71530, 940200)));
+        assert_eq!(Day06::part_one(&(races, kerned_race)), Ok(288));
+    }
+
+    #[test]
+    fn it_reports_a_kerned_race_too_large_only_in_part_two() {
+        let sheet = "\
+Time:      7  15   30   30   30   30   30   30   30   30   30
+Distance:  9  40  200  200  200  200  200  200  200  200  200";
+
+        let input = Day06::parse(sheet).unwrap();
+
+        assert_eq!(Day06::part_one(&input), Ok(288 * 9_u64.pow(8)));
+        assert_eq!(
+            Day06::part_two(&input).unwrap().unwrap_err().to_string(),
+            "day 6, line 1, column 12: the number read without spaces is too large, found \
+             `7  15   30   30   30   30   30   30   30   30   30`"
+        );
+    }
+
+    #[test]
+    fn it_reports_invalid_race_sheets() {
+        let missing_distances = "Time:      7  15   30";
+        assert_eq!(
+            parse_race_sheet(missing_distances).unwrap_err().to_string(),
+            "day 6, line 2, column 1: expected a `Distance:` line, found ``"
+        );
+
+        let missing_race = "\
+Time:      7  15   30
+Distance:  9  40";
+        assert_eq!(
+            parse_race_sheet(missing_race).unwrap_err().to_string(),
+            "day 6, line 2, column 1: expected as many distances as times, 3 times were found, found `Distance:  9  40`"
+        );
+
+        let invalid_time = "\
+Time:      7  1x   30
+Distance:  9  40  200";
+        assert_eq!(
+            parse_race_sheet(invalid_time).unwrap_err().to_string(),
+            "day 6, line 1, column 15: expected a number, found `1x`"
+        );
+    }
+}
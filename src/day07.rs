@@ -0,0 +1,353 @@
+use std::{
+    cmp::Ordering,
+    collections::HashMap,
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use crate::{
+    error::{Line, ParseError},
+    Solution,
+};
+
+const DAY: u8 = 7;
+
+/// The value of a `J` card in Joker mode, weaker than any other card.
+pub const JOKER: usize = 1;
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input<'a> = Vec<Hand>;
+    type Output = usize;
+
+    const HAS_PART_TWO: bool = true;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(index, line)| Hand::from_str(line).map_err(|err| err.at_line(index + 1)))
+            .collect()
+    }
+
+    fn part_one(hands: &Self::Input<'_>) -> Self::Output {
+        total_winnings(hands)
+    }
+
+    fn part_two(hands: &Self::Input<'_>) -> Option<Self::Output> {
+        Some(total_winnings_with_jokers(hands))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum HandType {
+    FiveOfAKind,
+    FourOfAKind,
+    FullHouse,
+    ThreeOfAKind,
+    TwoPair,
+    OnePair,
+    HighCard,
+}
+
+impl HandType {
+    /// Jokers stand in for whichever card makes the strongest hand.
+    pub fn from_cards(cards: [usize; 5]) -> Self {
+        let mut frequency: HashMap<usize, usize> = HashMap::new();
+        for card in cards.into_iter().filter(|&card| card != JOKER) {
+            frequency
+                .entry(card)
+                .and_modify(|freq| *freq += 1)
+                .or_insert(1);
+        }
+
+        // Adding the jokers to the most frequent card always makes the best hand.
+        let nb_jokers = cards.iter().filter(|&&card| card == JOKER).count();
+        match frequency.values_mut().max() {
+            Some(freq) => *freq += nb_jokers,
+            None => return Self::FiveOfAKind,
+        }
+
+        match frequency.len() {
+            1 => Self::FiveOfAKind,
+            2 => {
+                if frequency.values().any(|v| *v == 4) {
+                    Self::FourOfAKind
+                } else {
+                    Self::FullHouse
+                }
+            }
+            3 => {
+                if frequency.values().any(|v| *v == 3) {
+                    Self::ThreeOfAKind
+                } else {
+                    Self::TwoPair
+                }
+            }
+            4 => Self::OnePair,
+            5 => Self::HighCard,
+            _ => panic!("Somehow your hands is not a valid one."),
+        }
+    }
+
+    pub fn as_rank(&self) -> usize {
+        match self {
+            Self::FiveOfAKind => 7,
+            Self::FourOfAKind => 6,
+            Self::FullHouse => 5,
+            Self::ThreeOfAKind => 4,
+            Self::TwoPair => 3,
+            Self::OnePair => 2,
+            Self::HighCard => 1,
+        }
+    }
+}
+
+impl PartialOrd for HandType {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for HandType {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_rank().cmp(&other.as_rank())
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Hand {
+    pub cards: [usize; 5],
+    pub hand_type: HandType,
+    pub bid: usize,
+}
+
+impl FromStr for Hand {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        static RE: Lazy<Regex> =
+            Lazy::new(|| Regex::new(r"^(?<cards>[AKQJT98765432]{5})\s(?<bid>\d+)$").unwrap());
+
+        let line = Line::new(DAY, 0, s);
+        let caps = RE
+            .captures(s)
+            .ok_or_else(|| line.error(s, "expected 5 cards among `AKQJT98765432` and a bid"))?;
+
+        let cards: [usize; 5] = caps
+            .name("cards")
+            .unwrap()
+            .as_str()
+            .chars()
+            .map(|c| match c {
+                'A' => 14,
+                'K' => 13,
+                'Q' => 12,
+                'J' => 11,
+                'T' => 10,
+                n => n.to_string().parse().unwrap(),
+            })
+            .take(5)
+            .collect::<Vec<usize>>()
+            .try_into()
+            .unwrap();
+
+        let hand_type = HandType::from_cards(cards);
+
+        let bid: usize = line.parse_number(caps.name("bid").unwrap().as_str())?;
+
+        Ok(Hand {
+            cards,
+            hand_type,
+            bid,
+        })
+    }
+}
+
+impl Hand {
+    /// The same hand in Joker mode, where `J` cards are wildcards.
+    pub fn with_jokers(&self) -> Hand {
+        let cards = self.cards.map(|card| match card {
+            11 => JOKER,
+            card => card,
+        });
+
+        Hand {
+            cards,
+            hand_type: HandType::from_cards(cards),
+            bid: self.bid,
+        }
+    }
+}
+
+impl Display for Hand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let cards: String = self
+            .cards
+            .iter()
+            .map(|&card| match card {
+                14 => 'A',
+                13 => 'K',
+                12 => 'Q',
+                11 | JOKER => 'J',
+                10 => 'T',
+                n => char::from_digit(n as u32, 10).unwrap(),
+            })
+            .collect();
+        write!(f, "{} {}", cards, self.bid)
+    }
+}
+
+impl PartialOrd for Hand {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Hand {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match self.hand_type.cmp(&other.hand_type) {
+            Ordering::Equal => self.cards.cmp(&other.cards),
+            ord => ord,
+        }
+    }
+}
+
+pub fn total_winnings(hands: &[Hand]) -> usize {
+    let mut hands: Vec<&Hand> = hands.iter().collect();
+    hands.sort();
+
+    hands
+        .iter()
+        .enumerate()
+        .fold(0, |acc, (rank, hand)| acc + (rank + 1) * hand.bid)
+}
+
+pub fn total_winnings_with_jokers(hands: &[Hand]) -> usize {
+    let hands: Vec<Hand> = hands.iter().map(Hand::with_jokers).collect();
+
+    total_winnings(&hands)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn it_creates_hand_from_str() {
+        let hand_str = "32T3K 765";
+
+        let expected = Hand {
+            cards: [3, 2, 10, 3, 13],
+            hand_type: HandType::OnePair,
+            bid: 765,
+        };
+        let result = Hand::from_str(hand_str).unwrap();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn it_reports_invalid_hands() {
+        let hands_str = "\
+32T3K 765
+T55X5 684";
+
+        let result = Day07::parse(hands_str).unwrap_err();
+        assert_eq!(
+            result.to_string(),
+            "day 7, line 2, column 1: expected 5 cards among `AKQJT98765432` and a bid, found `T55X5 684`"
+        );
+    }
+
+    #[test]
+    fn it_compares_hands() {
+        let one_pair = Hand {
+            cards: [3, 2, 10, 3, 13],
+            hand_type: HandType::OnePair,
+            bid: 0,
+        };
+        let one_pair_eq = Hand {
+            cards: [3, 2, 10, 3, 13],
+            hand_type: HandType::OnePair,
+            bid: 0,
+        };
+        let two_pair_greater = Hand {
+            cards: [13, 13, 6, 7, 7],
+            hand_type: HandType::TwoPair,
+            bid: 0,
+        };
+        let two_pair_lesser = Hand {
+            cards: [13, 10, 11, 11, 10],
+            hand_type: HandType::TwoPair,
+            bid: 0,
+        };
+
+        assert_eq!(one_pair, one_pair_eq);
+        assert!(two_pair_greater >= one_pair);
+        assert!(two_pair_greater >= two_pair_lesser);
+
+        assert!(two_pair_greater > two_pair_lesser);
+    }
+
+    #[test]
+    fn it_does_everything_right() {
+        let hands_str = "\
+32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483";
+
+        let mut hands: Vec<Hand> = hands_str
+            .lines()
+            .map(|line| Hand::from_str(line).unwrap())
+            .collect();
+        hands.sort();
+        let result = hands
+            .iter()
+            .enumerate()
+            .fold(0, |acc, (rank, hand)| acc + (rank + 1) * hand.bid);
+
+        assert_eq!(result, 6440)
+    }
+
+    #[test]
+    fn it_uses_jokers_as_wildcards() {
+        let hand = Hand::from_str("KTJJT 220").unwrap().with_jokers();
+
+        assert_eq!(hand.cards, [13, 10, JOKER, JOKER, 10]);
+        assert_eq!(hand.hand_type, HandType::FourOfAKind);
+        assert_eq!(
+            Hand::from_str("JJJJJ 1").unwrap().with_jokers().hand_type,
+            HandType::FiveOfAKind
+        );
+    }
+
+    #[test]
+    fn it_breaks_ties_with_jokers_as_the_weakest_card() {
+        let jokers = Hand::from_str("JKKK2 1").unwrap().with_jokers();
+        let queens = Hand::from_str("QQQQ2 1").unwrap().with_jokers();
+
+        assert_eq!(jokers.hand_type, queens.hand_type);
+        assert!(jokers < queens);
+    }
+
+    #[test]
+    fn it_totals_winnings_with_jokers() {
+        let hands_str = "\
+32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483";
+        let hands = Day07::parse(hands_str).unwrap();
+
+        assert_eq!(total_winnings(&hands), 6440);
+        assert_eq!(total_winnings_with_jokers(&hands), 5905);
+    }
+}
@@ -0,0 +1,1270 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+use num::Integer;
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use crate::{
+    error::{Line, ParseError},
+    Solution,
+};
+
+const DAY: u8 = 8;
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input<'a> = CompiledMap<'a>;
+    type Output = Result<usize, WalkError>;
+
+    const HAS_PART_TWO: bool = true;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Ok(DesertMap::from_str(input)?.compile())
+    }
+
+    fn part_one(desert_map: &Self::Input<'_>) -> Self::Output {
+        desert_map.find_the_exit()
+    }
+
+    fn part_two(desert_map: &Self::Input<'_>) -> Option<Self::Output> {
+        Some(desert_map.find_the_exit_the_ghostly_way())
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum WalkError {
+    /// The walk is back in a state it has already been in without meeting the exit.
+    ExitUnreachable,
+    /// The ghosts are never all on an exit at the same step.
+    NoCommonExit,
+    /// The loops of the ghosts are too long to check whether they meet on an exit.
+    TooManySteps,
+    UnknownNode(String),
+    /// Describes the start node that was expected.
+    MissingStart(String),
+}
+
+impl Display for WalkError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WalkError::ExitUnreachable => write!(f, "the exit is never reached, the walk loops"),
+            WalkError::NoCommonExit => write!(f, "the ghosts are never all on an exit together"),
+            WalkError::TooManySteps => {
+                write!(
+                    f,
+                    "the loops of the ghosts are too long to find a common exit"
+                )
+            }
+            WalkError::UnknownNode(node) => {
+                write!(f, "node `{}` is followed but never defined", node)
+            }
+            WalkError::MissingStart(start) => write!(f, "no start node {}", start),
+        }
+    }
+}
+
+impl std::error::Error for WalkError {}
+
+/// The walk of a ghost, which loops forever once it is back in a state, a node reached at a
+/// given position of the directions, it has already been in.
+#[derive(Debug, PartialEq)]
+pub struct GhostCycle {
+    /// Steps taken before entering the loop.
+    pub prefix: usize,
+    /// Steps taken by one turn of the loop.
+    pub cycle: usize,
+    /// Steps at which the ghost is on an exit, over the prefix and the first turn of the loop.
+    pub exits: Vec<usize>,
+}
+
+impl GhostCycle {
+    pub fn is_on_an_exit(&self, step: usize) -> bool {
+        let step = match step < self.prefix + self.cycle {
+            true => step,
+            false => self.prefix + (step - self.prefix) % self.cycle,
+        };
+        self.exits.binary_search(&step).is_ok()
+    }
+
+    /// The exits met again on every turn of the loop.
+    fn looping_exits(&self) -> impl Iterator<Item = usize> + '_ {
+        self.exits
+            .iter()
+            .copied()
+            .filter(|&step| step >= self.prefix)
+    }
+}
+
+/// Selects the nodes a walk starts from or exits on.
+#[derive(Debug, Clone)]
+pub enum NodePattern {
+    Names(Vec<String>),
+    Prefix(String),
+    Suffix(String),
+    Regex(Regex),
+}
+
+impl NodePattern {
+    pub fn name(name: &str) -> Self {
+        NodePattern::Names(vec![name.to_string()])
+    }
+
+    pub fn matches(&self, node: &str) -> bool {
+        match self {
+            NodePattern::Names(names) => names.iter().any(|name| name == node),
+            NodePattern::Prefix(prefix) => node.starts_with(prefix.as_str()),
+            NodePattern::Suffix(suffix) => node.ends_with(suffix.as_str()),
+            NodePattern::Regex(regex) => regex.is_match(node),
+        }
+    }
+}
+
+/// Reads `/<regex>/`, `<prefix>*`, `*<suffix>`, or names separated by commas.
+impl FromStr for NodePattern {
+    type Err = regex::Error;
+
+    fn from_str(pattern: &str) -> Result<Self, Self::Err> {
+        if let Some(regex) = pattern.strip_prefix('/').and_then(|p| p.strip_suffix('/')) {
+            return Ok(NodePattern::Regex(Regex::new(regex)?));
+        }
+        if let Some(suffix) = pattern.strip_prefix('*') {
+            return Ok(NodePattern::Suffix(suffix.to_string()));
+        }
+        if let Some(prefix) = pattern.strip_suffix('*') {
+            return Ok(NodePattern::Prefix(prefix.to_string()));
+        }
+        Ok(NodePattern::Names(
+            pattern
+                .split(',')
+                .map(|name| name.trim().to_string())
+                .collect(),
+        ))
+    }
+}
+
+impl Display for NodePattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NodePattern::Names(names) => {
+                let names: Vec<String> = names.iter().map(|name| format!("`{}`", name)).collect();
+                write!(f, "{}", names.join(" or "))
+            }
+            NodePattern::Prefix(prefix) => write!(f, "starting with `{}`", prefix),
+            NodePattern::Suffix(suffix) => write!(f, "ending with `{}`", suffix),
+            NodePattern::Regex(regex) => write!(f, "matching `{}`", regex),
+        }
+    }
+}
+
+/// Finds the smallest `x >= lower_bound` with `x ≡ a (mod m)` for every `(a, m)` congruence,
+/// whether or not the moduli are coprime.
+fn solve_congruences(congruences: &[(usize, usize)], lower_bound: usize) -> Option<usize> {
+    let (residue, modulus) =
+        congruences
+            .iter()
+            .try_fold((0_i128, 1_i128), |(a1, m1), &(a2, m2)| {
+                let (a2, m2) = (a2 as i128, m2 as i128);
+                let euclid = m1.extended_gcd(&m2);
+                let gcd = euclid.gcd;
+                if (a2 - a1) % gcd != 0 {
+                    return None;
+                }
+                // m1 * euclid.x ≡ gcd (mod m2), so a1 + m1 * k solves both once scaled.
+                let lcm = m1 / gcd * m2;
+                let k = ((a2 - a1) / gcd * euclid.x).rem_euclid(m2 / gcd);
+                Some(((a1 + m1 * k).rem_euclid(lcm), lcm))
+            })?;
+
+    let lower_bound = lower_bound as i128;
+    let steps = match residue < lower_bound {
+        true => residue + (lower_bound - residue + modulus - 1) / modulus * modulus,
+        false => residue,
+    };
+    usize::try_from(steps).ok()
+}
+
+/// Where a walk is at one of its steps, and the direction it follows from there.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WalkStep<'a> {
+    pub step: usize,
+    /// Position of the direction in the map's directions.
+    pub direction_index: usize,
+    pub direction: char,
+    pub node: &'a str,
+}
+
+/// An endless walk through the network, see [`DesertMap::walk`].
+pub struct Walk<'m, 'a> {
+    desert_map: &'m DesertMap<'a>,
+    step: usize,
+    node: Option<Result<&'a str, WalkError>>,
+}
+
+impl<'a> Iterator for Walk<'_, 'a> {
+    type Item = Result<WalkStep<'a>, WalkError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = match self.node.take()? {
+            Ok(node) => node,
+            Err(err) => return Some(Err(err)),
+        };
+        let direction_index = self.step % self.desert_map.directions.len();
+        let walk_step = WalkStep {
+            step: self.step,
+            direction_index,
+            direction: self.desert_map.directions[direction_index],
+            node,
+        };
+
+        self.node = Some(self.desert_map.next_node(node, direction_index));
+        self.step += 1;
+        Some(Ok(walk_step))
+    }
+}
+
+/// A walk drawn over the network by [`DesertMap::to_dot`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PathOverlay<'s> {
+    /// The walk of [`DesertMap::find_the_exit`], from `AAA` to `ZZZ`.
+    Exit,
+    /// The walk of the ghost starting on this node, until it loops.
+    Ghost(&'s str),
+}
+
+/// Quotes a node name as a DOT identifier.
+fn dot_id(name: &str) -> String {
+    format!("\"{}\"", name.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Node<'a> {
+    pub left: &'a str,
+    pub right: &'a str,
+}
+
+impl<'a> Node<'a> {
+    pub fn new(left: &'a str, right: &'a str) -> Self {
+        Node { left, right }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct DesertMap<'a> {
+    pub directions: Vec<char>,
+    pub network: HashMap<&'a str, Node<'a>>,
+}
+
+impl<'a> DesertMap<'a> {
+    pub fn new(directions: Vec<char>, network: HashMap<&'a str, Node<'a>>) -> Self {
+        DesertMap {
+            directions,
+            network,
+        }
+    }
+
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(string: &'a str) -> Result<Self, ParseError> {
+        static RE_DIR: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?<directions>^[LR]+$)").unwrap());
+        // Any name without spaces or the `=(),` punctuation of the format, in any script.
+        static RE_NET: Lazy<Regex> = Lazy::new(|| {
+            Regex::new(r"^(?<node>[^\s=(),]+) = \((?<left>[^\s=(),]+), (?<right>[^\s=(),]+)\)")
+                .unwrap()
+        });
+
+        let mut lines = string.lines().enumerate();
+
+        let (index, directions_line) = lines
+            .next()
+            .ok_or_else(|| ParseError::new(DAY, 1, 1, "", "expected a line of directions"))?;
+        let directions = RE_DIR
+            .captures(directions_line)
+            .map(|caps| caps.name("directions").unwrap().as_str().chars().collect())
+            .ok_or_else(|| {
+                Line::new(DAY, index, directions_line)
+                    .error(directions_line, "expected a line of `L` and `R` directions")
+            })?;
+
+        if let Some((index, l)) = lines.next() {
+            if !l.is_empty() {
+                return Err(Line::new(DAY, index, l).error(l, "expected an empty line"));
+            }
+        }
+
+        let mut network = HashMap::new();
+        for (index, l) in lines {
+            let caps = RE_NET.captures(l).ok_or_else(|| {
+                Line::new(DAY, index, l).error(l, "expected `<node> = (<left>, <right>)`")
+            })?;
+            let node = caps.name("node").unwrap().as_str();
+            let left = caps.name("left").unwrap().as_str();
+            let right = caps.name("right").unwrap().as_str();
+
+            network.insert(node, Node::new(left, right));
+        }
+
+        Ok(DesertMap::new(directions, network))
+    }
+
+    /// The node reached from `node_name` by following the direction at `direction_index`.
+    fn next_node(&self, node_name: &str, direction_index: usize) -> Result<&'a str, WalkError> {
+        let node = self
+            .network
+            .get(node_name)
+            .ok_or_else(|| WalkError::UnknownNode(node_name.to_string()))?;
+
+        match self.directions[direction_index] {
+            'L' => Ok(node.left),
+            'R' => Ok(node.right),
+            _ => panic!(),
+        }
+    }
+
+    /// Numbers the nodes, see [`CompiledMap`]. Walking the compiled map rather than this one
+    /// saves compiling the network again for each walk.
+    pub fn compile(&self) -> CompiledMap<'a> {
+        // Each node follows at most two nodes missing from the network.
+        assert!(
+            self.network.len().saturating_mul(3) <= u32::MAX as usize,
+            "too many nodes to number them with u32"
+        );
+        let nodes: Vec<(&&'a str, &Node<'a>)> = self.network.iter().collect();
+        let mut names: Vec<&'a str> = nodes.iter().map(|(&name, _)| name).collect();
+
+        // Followed nodes missing from the network are numbered after the defined ones.
+        let mut indices: HashMap<&'a str, u32> = HashMap::with_capacity(names.len());
+        for (index, &name) in names.iter().enumerate() {
+            indices.insert(name, index as u32);
+        }
+        let mut links = Vec::with_capacity(nodes.len());
+        for (_, node) in nodes {
+            let mut link = [0; 2];
+            for (side, name) in [node.left, node.right].into_iter().enumerate() {
+                link[side] = *indices.entry(name).or_insert_with(|| {
+                    names.push(name);
+                    (names.len() - 1) as u32
+                });
+            }
+            links.push(link);
+        }
+
+        let mut direction_bits = vec![0_u64; self.directions.len().div_ceil(64)];
+        for (index, &direction) in self.directions.iter().enumerate() {
+            if direction == 'R' {
+                direction_bits[index / 64] |= 1 << (index % 64);
+            }
+        }
+
+        CompiledMap {
+            names,
+            indices,
+            links,
+            direction_bits,
+            directions_len: self.directions.len(),
+        }
+    }
+
+    /// Follows the directions from `start`, forever. The walk ends with an error if it reaches
+    /// a node missing from the network, once it has stepped on it.
+    pub fn walk<'m>(&'m self, start: &'a str) -> Walk<'m, 'a> {
+        Walk {
+            desert_map: self,
+            step: 0,
+            node: Some(Ok(start)),
+        }
+    }
+
+    pub fn find_the_exit(&self) -> Result<usize, WalkError> {
+        self.compile().find_the_exit()
+    }
+
+    /// The first step, after leaving `start`, on a node matched by `exits`.
+    pub fn find_an_exit(&self, start: &str, exits: &NodePattern) -> Result<usize, WalkError> {
+        let compiled_map = self.compile();
+        compiled_map.find_an_exit(compiled_map.start(start)?, &compiled_map.matching(exits))
+    }
+
+    /// Walks from `start` until a state repeats, noting every step spent on a node matched by
+    /// `exits`.
+    pub fn ghost_cycle(
+        &self,
+        start: &'a str,
+        exits: &NodePattern,
+    ) -> Result<GhostCycle, WalkError> {
+        let compiled_map = self.compile();
+        compiled_map.ghost_cycle(compiled_map.start(start)?, &compiled_map.matching(exits))
+    }
+
+    /// The first step, after leaving, where every ghost starting on a node ending with `A` is on
+    /// a node ending with `Z`.
+    pub fn find_the_exit_the_ghostly_way(&self) -> Result<usize, WalkError> {
+        self.compile().find_the_exit_the_ghostly_way()
+    }
+
+    /// The first step, after leaving, where every ghost starting on a node matched by `starts`
+    /// is on a node matched by `exits`.
+    pub fn find_the_exit_of_ghosts(
+        &self,
+        starts: &NodePattern,
+        exits: &NodePattern,
+    ) -> Result<usize, WalkError> {
+        self.compile().find_the_exit_of_ghosts(starts, exits)
+    }
+
+    /// The edges, as a node and the direction taken from it, followed from `start` until the
+    /// walk is back in a state it has already been in, meets an unknown node, or, when
+    /// `stop_at_exit`, reaches `ZZZ`.
+    fn walked_edges(&self, start: &'a str, stop_at_exit: bool) -> HashSet<(&'a str, char)> {
+        let mut visited = HashSet::new();
+        let mut edges = HashSet::new();
+        for walk_step in self.walk(start).map_while(Result::ok) {
+            if (stop_at_exit && walk_step.node == "ZZZ" && walk_step.step > 0)
+                || !visited.insert((walk_step.node, walk_step.direction_index))
+                || !self.network.contains_key(walk_step.node)
+            {
+                break;
+            }
+            edges.insert((walk_step.node, walk_step.direction));
+        }
+        edges
+    }
+
+    /// The network as a Graphviz DOT graph, with `L` and `R` labelled edges, start nodes ending
+    /// with `A` and end nodes ending with `Z` filled, and the edges of the `overlay` walk in red.
+    pub fn to_dot(&self, overlay: Option<PathOverlay>) -> Result<String, WalkError> {
+        let path = match overlay {
+            None => HashSet::new(),
+            Some(PathOverlay::Exit) => match self.network.get_key_value("AAA") {
+                Some((&start, _)) => self.walked_edges(start, true),
+                None => return Err(WalkError::MissingStart("`AAA`".to_string())),
+            },
+            Some(PathOverlay::Ghost(ghost)) => match self.network.get_key_value(ghost) {
+                Some((&start, _)) => self.walked_edges(start, false),
+                None => return Err(WalkError::MissingStart(format!("`{}`", ghost))),
+            },
+        };
+
+        let mut names: Vec<&&str> = self.network.keys().collect();
+        names.sort();
+
+        let mut dot = String::from("digraph desert {\n");
+        for name in &names {
+            let fill = match (name.ends_with('A'), name.ends_with('Z')) {
+                (true, _) => "palegreen",
+                (_, true) => "lightsalmon",
+                _ => continue,
+            };
+            dot += &format!("    {} [style=filled, fillcolor={}];\n", dot_id(name), fill);
+        }
+        for name in names {
+            let node = &self.network[name];
+            for (direction, next) in [('L', node.left), ('R', node.right)] {
+                let colour = match path.contains(&(*name, direction)) {
+                    true => ", color=red, penwidth=2",
+                    false => "",
+                };
+                dot += &format!(
+                    "    {} -> {} [label=\"{}\"{}];\n",
+                    dot_id(name),
+                    dot_id(next),
+                    direction,
+                    colour
+                );
+            }
+        }
+        dot += "}\n";
+        Ok(dot)
+    }
+}
+
+/// A [`DesertMap`] with its nodes numbered, the defined ones first, so that walks follow a flat
+/// table rather than looking names up, and detect loops without remembering the states they
+/// went through.
+#[derive(Debug)]
+pub struct CompiledMap<'a> {
+    names: Vec<&'a str>,
+    indices: HashMap<&'a str, u32>,
+    /// Left and right nodes of the defined nodes.
+    links: Vec<[u32; 2]>,
+    /// The `i`th bit is set when the `i`th direction is `R`.
+    direction_bits: Vec<u64>,
+    directions_len: usize,
+}
+
+/// How a walk searched by [`CompiledMap::search_loop`] ended.
+enum LoopSearch {
+    Stopped { step: usize },
+    Loops { cycle: usize },
+}
+
+impl<'a> CompiledMap<'a> {
+    /// The number of a node defined in the network.
+    pub fn index(&self, name: &str) -> Option<u32> {
+        self.indices
+            .get(name)
+            .copied()
+            .filter(|&index| (index as usize) < self.links.len())
+    }
+
+    fn start(&self, name: &str) -> Result<u32, WalkError> {
+        self.index(name)
+            .ok_or_else(|| WalkError::MissingStart(format!("`{}`", name)))
+    }
+
+    pub fn name(&self, index: u32) -> &'a str {
+        self.names[index as usize]
+    }
+
+    /// Whether each node, by number, is matched by `pattern`.
+    pub fn matching(&self, pattern: &NodePattern) -> Vec<bool> {
+        self.names
+            .iter()
+            .map(|name| pattern.matches(name))
+            .collect()
+    }
+
+    pub fn next_node(&self, node: u32, direction_index: usize) -> Result<u32, WalkError> {
+        let link = self.links.get(node as usize).ok_or_else(|| {
+            WalkError::UnknownNode(match self.names.get(node as usize) {
+                Some(name) => name.to_string(),
+                None => format!("#{}", node),
+            })
+        })?;
+        let side = self.direction_bits[direction_index / 64] >> (direction_index % 64) & 1;
+        Ok(link[side as usize])
+    }
+
+    /// The state after `(node, direction_index)`.
+    fn advance(&self, (node, direction_index): (u32, usize)) -> Result<(u32, usize), WalkError> {
+        let next_direction_index = match direction_index + 1 == self.directions_len {
+            true => 0,
+            false => direction_index + 1,
+        };
+        Ok((self.next_node(node, direction_index)?, next_direction_index))
+    }
+
+    /// Walks from `start` until `stop` is true for a step after the first, or until Brent's
+    /// algorithm finds the loop the walk ends in.
+    fn search_loop(
+        &self,
+        start: u32,
+        mut stop: impl FnMut(u32) -> bool,
+    ) -> Result<LoopSearch, WalkError> {
+        let (mut power, mut cycle) = (1, 1);
+        let mut tortoise = (start, 0);
+        let mut hare = self.advance(tortoise)?;
+        for step in 1.. {
+            if stop(hare.0) {
+                return Ok(LoopSearch::Stopped { step });
+            }
+            if tortoise == hare {
+                return Ok(LoopSearch::Loops { cycle });
+            }
+            if power == cycle {
+                tortoise = hare;
+                power *= 2;
+                cycle = 0;
+            }
+            hare = self.advance(hare)?;
+            cycle += 1;
+        }
+        unreachable!()
+    }
+
+    pub fn find_the_exit(&self) -> Result<usize, WalkError> {
+        self.find_an_exit(
+            self.start("AAA")?,
+            &self.matching(&NodePattern::name("ZZZ")),
+        )
+    }
+
+    /// The first step, after leaving `start`, on one of the `exits`.
+    pub fn find_an_exit(&self, start: u32, exits: &[bool]) -> Result<usize, WalkError> {
+        match self.search_loop(start, |node| exits[node as usize])? {
+            LoopSearch::Stopped { step } => Ok(step),
+            LoopSearch::Loops { .. } => Err(WalkError::ExitUnreachable),
+        }
+    }
+
+    /// Walks from `start` until a state repeats, noting every step spent on one of the `exits`.
+    pub fn ghost_cycle(&self, start: u32, exits: &[bool]) -> Result<GhostCycle, WalkError> {
+        let LoopSearch::Loops { cycle } = self.search_loop(start, |_| false)? else {
+            unreachable!()
+        };
+
+        // A walker one turn ahead meets the other one where the loop starts.
+        let mut behind = (start, 0);
+        let mut ahead = behind;
+        for _ in 0..cycle {
+            ahead = self.advance(ahead)?;
+        }
+        let mut prefix = 0;
+        while behind != ahead {
+            behind = self.advance(behind)?;
+            ahead = self.advance(ahead)?;
+            prefix += 1;
+        }
+
+        let mut exit_steps = Vec::new();
+        let mut state = (start, 0);
+        for step in 0..prefix + cycle {
+            if exits[state.0 as usize] {
+                exit_steps.push(step);
+            }
+            state = self.advance(state)?;
+        }
+
+        Ok(GhostCycle {
+            prefix,
+            cycle,
+            exits: exit_steps,
+        })
+    }
+
+    /// The first step, after leaving, where every ghost starting on a node ending with `A` is on
+    /// a node ending with `Z`.
+    pub fn find_the_exit_the_ghostly_way(&self) -> Result<usize, WalkError> {
+        self.find_the_exit_of_ghosts(
+            &NodePattern::Suffix("A".to_string()),
+            &NodePattern::Suffix("Z".to_string()),
+        )
+    }
+
+    /// The first step, after leaving, where every ghost starting on a node matched by `starts`
+    /// is on a node matched by `exits`.
+    pub fn find_the_exit_of_ghosts(
+        &self,
+        starts: &NodePattern,
+        exits: &NodePattern,
+    ) -> Result<usize, WalkError> {
+        let exits = self.matching(exits);
+        let cycles: Vec<GhostCycle> = self
+            .matching(starts)
+            .iter()
+            .take(self.links.len())
+            .enumerate()
+            .filter(|(_, &is_start)| is_start)
+            .map(|(start, _)| self.ghost_cycle(start as u32, &exits))
+            .collect::<Result<_, _>>()?;
+        if cycles.is_empty() {
+            return Err(WalkError::MissingStart(starts.to_string()));
+        }
+
+        // An exit met before a loop happens once: every ghost has to be on an exit then too.
+        let before_looping = cycles
+            .iter()
+            .flat_map(|cycle| cycle.exits.iter().filter(|&&step| step < cycle.prefix))
+            .filter(|&&step| step > 0 && cycles.iter().all(|cycle| cycle.is_on_an_exit(step)))
+            .min()
+            .copied();
+
+        if let Some(step) = before_looping {
+            // Earlier than any step where the ghosts all are in their loops.
+            return Ok(step);
+        }
+
+        // Otherwise the ghosts all are in their loops, meeting one of its exits every turn.
+        let lower_bound = cycles
+            .iter()
+            .map(|cycle| cycle.prefix)
+            .max()
+            .unwrap_or(0)
+            .max(1);
+        first_looping_exit(&cycles, lower_bound)
+    }
+}
+
+/// Above this many combinations of exits, the ghosts left are checked step by step instead.
+const MAX_RESIDUES: usize = 1 << 16;
+
+/// Past this many steps checked one by one, the search gives up.
+const MAX_CHECKED_STEPS: usize = 1 << 22;
+
+/// The first step from `lower_bound`, where every ghost is in its loop, with all the ghosts on
+/// an exit.
+fn first_looping_exit(cycles: &[GhostCycle], lower_bound: usize) -> Result<usize, WalkError> {
+    let mut cycles: Vec<(&GhostCycle, Vec<usize>)> = cycles
+        .iter()
+        .map(|cycle| {
+            let exits = cycle
+                .looping_exits()
+                .map(|step| step % cycle.cycle)
+                .collect();
+            (cycle, exits)
+        })
+        .collect();
+    cycles.sort_by_key(|(_, exits)| exits.len());
+
+    // The steps where the ghosts combined so far all are on an exit, modulo their common period.
+    let mut residues = vec![0];
+    let mut modulus = 1_usize;
+    let mut combined = 0;
+    loop {
+        keep_matching_residues(&mut residues, modulus, &cycles[combined..]);
+        if residues.is_empty() {
+            return Err(WalkError::NoCommonExit);
+        }
+
+        let Some((cycle, exits)) = cycles.get(combined) else {
+            break;
+        };
+        let Some(next_modulus) = (modulus / modulus.gcd(&cycle.cycle)).checked_mul(cycle.cycle)
+        else {
+            break;
+        };
+        if residues.len() * exits.len() > MAX_RESIDUES {
+            break;
+        }
+
+        let mut next_residues: Vec<usize> = residues
+            .iter()
+            .flat_map(|&residue| {
+                exits.iter().filter_map(move |&exit| {
+                    solve_congruences(&[(residue, modulus), (exit, cycle.cycle)], 0)
+                })
+            })
+            .collect();
+        next_residues.sort_unstable();
+        next_residues.dedup();
+
+        residues = next_residues;
+        modulus = next_modulus;
+        combined += 1;
+    }
+
+    let remaining = &cycles[combined..];
+    if remaining.is_empty() {
+        return residues
+            .iter()
+            .filter_map(|&residue| solve_congruences(&[(residue, modulus)], lower_bound))
+            .min()
+            .ok_or(WalkError::NoCommonExit);
+    }
+
+    // The ghosts all are back where they were after a turn of every loop.
+    let period = remaining
+        .iter()
+        .try_fold(modulus, |period, (cycle, _)| {
+            (period / period.gcd(&cycle.cycle)).checked_mul(cycle.cycle)
+        })
+        .unwrap_or(usize::MAX);
+    let end = lower_bound.saturating_add(period);
+    let mut turn = lower_bound / modulus * modulus;
+    let mut checked = 0_usize;
+    while turn < end {
+        checked += residues.len();
+        if checked > MAX_CHECKED_STEPS {
+            return Err(WalkError::TooManySteps);
+        }
+        let step = residues
+            .iter()
+            .filter_map(|&residue| turn.checked_add(residue))
+            .filter(|&step| step >= lower_bound && step < end)
+            .find(|&step| remaining.iter().all(|(cycle, _)| cycle.is_on_an_exit(step)));
+        if let Some(step) = step {
+            return Ok(step);
+        }
+        let Some(next_turn) = turn.checked_add(modulus) else {
+            break;
+        };
+        turn = next_turn;
+    }
+    Err(WalkError::NoCommonExit)
+}
+
+/// Drops the residues some ghost left never is on an exit at: the steps `residue + k * modulus`
+/// only meet the steps of its exits that agree with `residue` modulo `gcd(modulus, cycle)`.
+fn keep_matching_residues(
+    residues: &mut Vec<usize>,
+    modulus: usize,
+    remaining: &[(&GhostCycle, Vec<usize>)],
+) {
+    for (cycle, exits) in remaining {
+        let divisor = modulus.gcd(&cycle.cycle);
+        if divisor == 1 {
+            continue;
+        }
+        let matched: HashSet<usize> = exits.iter().map(|exit| exit % divisor).collect();
+        residues.retain(|residue| matched.contains(&(residue % divisor)));
+    }
+}
+
+/// Nodes are written sorted by name, the network itself being unordered.
+impl Display for DesertMap<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{}", self.directions.iter().collect::<String>())?;
+        writeln!(f)?;
+
+        let mut names: Vec<&&str> = self.network.keys().collect();
+        names.sort();
+        for name in names {
+            let node = &self.network[name];
+            writeln!(f, "{} = ({}, {})", name, node.left, node.right)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::error::SolveError;
+
+    #[test]
+    fn it_parses_a_desert_map() {
+        let map_str = "\
+LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)";
+
+        let expected = DesertMap::new(
+            vec!['L', 'L', 'R'],
+            HashMap::from([
+                ("AAA", Node::new("BBB", "BBB")),
+                ("BBB", Node::new("AAA", "ZZZ")),
+                ("ZZZ", Node::new("ZZZ", "ZZZ")),
+            ]),
+        );
+        let result = DesertMap::from_str(map_str).unwrap();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn it_parses_any_node_names() {
+        let map_str = "\
+LR
+
+AAA = (oasis_north, ZZZ)
+oasis_north = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)
+дюна_A = (ʻāina, ʻāina)
+ʻāina = (oasis_Z, oasis_Z)
+oasis_Z = (дюна_A, дюна_A)";
+        let desert_map = DesertMap::from_str(map_str).unwrap();
+
+        assert_eq!(desert_map.network["oasis_north"], Node::new("AAA", "ZZZ"));
+        assert_eq!(desert_map.find_the_exit(), Ok(2));
+        assert_eq!(desert_map.find_the_exit_the_ghostly_way(), Ok(2));
+    }
+
+    #[test]
+    fn it_reports_invalid_nodes() {
+        let map_str = "\
+LLR
+
+AAA = (BBB, BBB)
+BBB = AAA, ZZZ";
+
+        let result = DesertMap::from_str(map_str).unwrap_err();
+        assert_eq!((result.line, result.column), (4, 1));
+        assert_eq!(result.text, "BBB = AAA, ZZZ");
+    }
+
+    #[test]
+    fn it_finds_the_steps_to_exit() {
+        let desert_map = DesertMap::new(
+            vec!['L', 'L', 'R'],
+            HashMap::from([
+                ("AAA", Node::new("BBB", "BBB")),
+                ("BBB", Node::new("AAA", "ZZZ")),
+                ("ZZZ", Node::new("ZZZ", "ZZZ")),
+            ]),
+        );
+        assert_eq!(desert_map.find_the_exit(), Ok(6))
+    }
+
+    #[test]
+    fn it_finds_the_exit_the_ghostly_way() {
+        let desert_str = "\
+LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)";
+
+        let desert_map = DesertMap::from_str(desert_str).unwrap();
+
+        assert_eq!(desert_map.find_the_exit_the_ghostly_way(), Ok(6));
+    }
+
+    #[test]
+    fn it_detects_ghost_cycles() {
+        let desert_str = "\
+LR
+
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)";
+        let desert_map = DesertMap::from_str(desert_str).unwrap();
+
+        assert_eq!(
+            desert_map.ghost_cycle("22A", &NodePattern::Suffix("Z".to_string())),
+            Ok(GhostCycle {
+                prefix: 1,
+                cycle: 6,
+                exits: vec![3, 6],
+            })
+        );
+    }
+
+    #[test]
+    fn it_finds_exits_met_before_looping() {
+        // The first ghost passes by its exit once, the second one every other step.
+        let desert_str = "\
+L
+
+11A = (11B, 11B)
+11B = (11Z, 11Z)
+11Z = (11C, 11C)
+11C = (11C, 11C)
+22A = (22Z, 22Z)
+22Z = (22A, 22A)";
+        let desert_map = DesertMap::from_str(desert_str).unwrap();
+
+        assert_eq!(
+            desert_map.find_the_exit_the_ghostly_way(),
+            Err(WalkError::NoCommonExit)
+        );
+
+        let desert_str =
+            desert_str.replace("22A = (22Z, 22Z)", "22A = (22B, 22B)\n22B = (22Z, 22Z)");
+        let desert_map = DesertMap::from_str(&desert_str).unwrap();
+
+        assert_eq!(desert_map.find_the_exit_the_ghostly_way(), Ok(2));
+    }
+
+    #[test]
+    fn it_finds_exits_of_cycles_with_offsets() {
+        // Exits every 4 steps from step 3, and every 6 steps from step 1: first together at 7.
+        let desert_str = "\
+L
+
+11A = (11B, 11B)
+11B = (11C, 11C)
+11C = (11Z, 11Z)
+11Z = (11D, 11D)
+11D = (11B, 11B)
+22A = (22Z, 22Z)
+22Z = (22B, 22B)
+22B = (22C, 22C)
+22C = (22D, 22D)
+22D = (22E, 22E)
+22E = (22F, 22F)
+22F = (22Z, 22Z)";
+        let desert_map = DesertMap::from_str(desert_str).unwrap();
+
+        assert_eq!(desert_map.find_the_exit_the_ghostly_way(), Ok(7));
+    }
+
+    #[test]
+    fn it_finds_exits_of_loops_full_of_exits() {
+        // Too many combinations of exits to go through them all.
+        let mut lines = Vec::new();
+        for (ghost, length) in [31, 37, 41, 43, 47].into_iter().enumerate() {
+            lines.push(format!("{}A = ({}_0Z, {}_0Z)", ghost, ghost, ghost));
+            for node in 0..length {
+                let next = (node + 1) % length;
+                lines.push(format!(
+                    "{}_{}Z = ({}_{}Z, {}_{}Z)",
+                    ghost, node, ghost, next, ghost, next
+                ));
+            }
+        }
+        let desert_str = format!("L\n\n{}", lines.join("\n"));
+        let desert_map = DesertMap::from_str(&desert_str).unwrap();
+
+        assert_eq!(desert_map.find_the_exit_the_ghostly_way(), Ok(1));
+
+        // A last ghost on an exit every 5 steps, from step 3.
+        let desert_str = format!(
+            "{}\n5A = (5_0X, 5_0X)\n5_0X = (5_1X, 5_1X)\n5_1X = (5_2Z, 5_2Z)\n\
+             5_2Z = (5_3X, 5_3X)\n5_3X = (5_4X, 5_4X)\n5_4X = (5_0X, 5_0X)",
+            desert_str
+        );
+        let desert_map = DesertMap::from_str(&desert_str).unwrap();
+
+        assert_eq!(desert_map.find_the_exit_the_ghostly_way(), Ok(3));
+    }
+
+    #[test]
+    fn it_rules_out_loops_whose_exits_never_agree() {
+        let looping = |cycle: usize, exits: Vec<usize>| GhostCycle {
+            prefix: 0,
+            cycle,
+            exits,
+        };
+        let even = || (0..300).map(|exit| 2 * exit).collect();
+        let odd = || (0..300).map(|exit| 2 * exit + 1).collect();
+
+        // Exits on even steps and exits on odd steps of loops of even lengths never meet.
+        let cycles = [
+            looping(20000, even()),
+            looping(20002, odd()),
+            looping(20004, (0..300).collect()),
+        ];
+        assert_eq!(first_looping_exit(&cycles, 1), Err(WalkError::NoCommonExit));
+
+        let cycles = [
+            looping(20004, (0..300).collect()),
+            looping(20000, even()),
+            looping(20002, odd()),
+        ];
+        assert_eq!(first_looping_exit(&cycles, 1), Err(WalkError::NoCommonExit));
+
+        // Without a loop of a length sharing a divisor with both to rule them out, give up.
+        let cycles = [
+            looping(20011, (0..300).collect()),
+            looping(20000, even()),
+            looping(20002, odd()),
+        ];
+        assert_eq!(first_looping_exit(&cycles, 1), Err(WalkError::TooManySteps));
+    }
+
+    #[test]
+    fn it_reports_unreachable_exits() {
+        let desert_str = "\
+LR
+
+AAA = (BBB, AAA)
+BBB = (AAA, BBB)
+ZZZ = (ZZZ, ZZZ)";
+        let desert_map = DesertMap::from_str(desert_str).unwrap();
+
+        assert_eq!(desert_map.find_the_exit(), Err(WalkError::ExitUnreachable));
+        assert_eq!(
+            crate::puzzle(DAY)
+                .unwrap()
+                .solve(desert_str, crate::Part::One),
+            Err(SolveError::NoAnswer(
+                "the exit is never reached, the walk loops".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn it_reports_unknown_nodes() {
+        let desert_str = "\
+L
+
+AAA = (BBB, BBB)";
+        let desert_map = DesertMap::from_str(desert_str).unwrap();
+
+        assert_eq!(
+            desert_map.find_the_exit(),
+            Err(WalkError::UnknownNode("BBB".to_string()))
+        );
+        assert_eq!(
+            desert_map.find_an_exit("AAA", &NodePattern::name("BBB")),
+            Ok(1)
+        );
+        assert_eq!(
+            desert_map.find_the_exit_the_ghostly_way(),
+            Err(WalkError::UnknownNode("BBB".to_string()))
+        );
+    }
+
+    #[test]
+    fn it_reports_missing_start_nodes() {
+        let desert_str = "\
+L
+
+BBB = (ZZZ, ZZZ)
+ZZZ = (ZZZ, ZZZ)";
+        let desert_map = DesertMap::from_str(desert_str).unwrap();
+
+        assert_eq!(
+            desert_map.find_the_exit().unwrap_err().to_string(),
+            "no start node `AAA`"
+        );
+        assert_eq!(
+            desert_map
+                .find_the_exit_the_ghostly_way()
+                .unwrap_err()
+                .to_string(),
+            "no start node ending with `A`"
+        );
+    }
+
+    #[test]
+    fn it_walks_step_by_step() {
+        let desert_str = "\
+LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)";
+        let desert_map = DesertMap::from_str(desert_str).unwrap();
+
+        let walk: Vec<WalkStep> = desert_map
+            .walk("AAA")
+            .skip(2)
+            .take(5)
+            .collect::<Result<_, _>>()
+            .unwrap();
+        let nodes: Vec<(usize, usize, char, &str)> = walk
+            .iter()
+            .map(|s| (s.step, s.direction_index, s.direction, s.node))
+            .collect();
+        assert_eq!(
+            nodes,
+            vec![
+                (2, 2, 'R', "AAA"),
+                (3, 0, 'L', "BBB"),
+                (4, 1, 'L', "AAA"),
+                (5, 2, 'R', "BBB"),
+                (6, 0, 'L', "ZZZ"),
+            ]
+        );
+    }
+
+    #[test]
+    fn it_ends_walks_on_unknown_nodes() {
+        let desert_map = DesertMap::from_str("L\n\nAAA = (BBB, BBB)").unwrap();
+
+        let walk: Vec<_> = desert_map.walk("AAA").collect();
+        assert_eq!(walk.len(), 3);
+        assert_eq!(walk[1].as_ref().unwrap().node, "BBB");
+        assert_eq!(walk[2], Err(WalkError::UnknownNode("BBB".to_string())));
+    }
+
+    #[test]
+    fn it_exports_the_network_to_dot() {
+        let desert_str = "\
+LR
+
+AAA = (BBB, ZZZ)
+BBB = (AAA, BBB)
+ZZZ = (ZZZ, ZZZ)";
+        let desert_map = DesertMap::from_str(desert_str).unwrap();
+
+        let expected = r#"digraph desert {
+    "AAA" [style=filled, fillcolor=palegreen];
+    "ZZZ" [style=filled, fillcolor=lightsalmon];
+    "AAA" -> "BBB" [label="L", color=red, penwidth=2];
+    "AAA" -> "ZZZ" [label="R", color=red, penwidth=2];
+    "BBB" -> "AAA" [label="L", color=red, penwidth=2];
+    "BBB" -> "BBB" [label="R", color=red, penwidth=2];
+    "ZZZ" -> "ZZZ" [label="L"];
+    "ZZZ" -> "ZZZ" [label="R"];
+}
+"#;
+        assert_eq!(desert_map.find_the_exit(), Ok(4));
+        assert_eq!(
+            desert_map.to_dot(Some(PathOverlay::Exit)).unwrap(),
+            expected
+        );
+        assert_eq!(
+            desert_map.to_dot(None).unwrap(),
+            expected.replace(", color=red, penwidth=2", "")
+        );
+    }
+
+    #[test]
+    fn it_overlays_the_walk_of_a_ghost() {
+        let desert_str = "\
+L
+
+11A = (11Z, 11Z)
+11Z = (11A, 11A)
+\"q\" = (11A, 11A)";
+        let desert_map = DesertMap::from_str(desert_str).unwrap();
+
+        let dot = desert_map.to_dot(Some(PathOverlay::Ghost("11A"))).unwrap();
+        assert!(dot.contains(r#""11A" -> "11Z" [label="L", color=red, penwidth=2];"#));
+        assert!(dot.contains(r#""11Z" -> "11A" [label="L", color=red, penwidth=2];"#));
+        assert!(dot.contains(r#""\"q\"" -> "11A" [label="L"];"#));
+        assert_eq!(
+            desert_map.to_dot(Some(PathOverlay::Ghost("22A"))),
+            Err(WalkError::MissingStart("`22A`".to_string()))
+        );
+    }
+
+    #[test]
+    fn it_finds_exits_of_any_nodes() {
+        let desert_str = "\
+L
+
+AAA = (BBB, BBB)
+BBB = (CCQ, CCQ)
+CCQ = (DDQ, DDQ)
+DDQ = (BBB, BBB)
+XXA = (XXB, XXB)
+XXB = (XXA, XXA)";
+        let desert_map = DesertMap::from_str(desert_str).unwrap();
+
+        let exits: NodePattern = "*Q".parse().unwrap();
+        assert_eq!(desert_map.find_an_exit("BBB", &exits), Ok(1));
+        assert_eq!(desert_map.find_an_exit("CCQ", &exits), Ok(1));
+        assert_eq!(
+            desert_map.find_an_exit("DDQ", &"BBB,AAA".parse().unwrap()),
+            Ok(1)
+        );
+        assert_eq!(
+            desert_map.find_an_exit("DDQ", &"AAA".parse().unwrap()),
+            Err(WalkError::ExitUnreachable)
+        );
+
+        let starts: NodePattern = "/^[AB]+$/".parse().unwrap();
+        assert_eq!(desert_map.find_the_exit_of_ghosts(&starts, &exits), Ok(2));
+        assert_eq!(
+            desert_map.find_the_exit_of_ghosts(&"XX*".parse().unwrap(), &"XX*".parse().unwrap()),
+            Ok(1)
+        );
+        assert_eq!(
+            desert_map
+                .find_the_exit_of_ghosts(&"YY*".parse().unwrap(), &exits)
+                .unwrap_err()
+                .to_string(),
+            "no start node starting with `YY`"
+        );
+    }
+
+    #[test]
+    fn it_compiles_the_network() {
+        let desert_map = DesertMap::from_str("RL\n\nBBB = (AAA, CCC)\nAAA = (BBB, BBB)").unwrap();
+        let compiled_map = desert_map.compile();
+
+        let aaa = compiled_map.index("AAA").unwrap();
+        let bbb = compiled_map.index("BBB").unwrap();
+        assert_eq!(compiled_map.index("CCC"), None);
+        assert_eq!(compiled_map.name(2), "CCC");
+        assert_eq!(compiled_map.next_node(bbb, 0), Ok(2));
+        assert_eq!(compiled_map.next_node(bbb, 1), Ok(aaa));
+        assert_eq!(
+            compiled_map.next_node(2, 0),
+            Err(WalkError::UnknownNode("CCC".to_string()))
+        );
+        assert_eq!(
+            compiled_map.next_node(7, 0),
+            Err(WalkError::UnknownNode("#7".to_string()))
+        );
+
+        let matching = compiled_map.matching(&"BBB,CCC".parse().unwrap());
+        assert_eq!(
+            (matching[aaa as usize], matching[bbb as usize], matching[2]),
+            (false, true, true)
+        );
+    }
+
+    #[test]
+    fn it_solves_congruences_with_common_factors() {
+        assert_eq!(solve_congruences(&[(3, 4), (1, 6)], 0), Some(7));
+        assert_eq!(solve_congruences(&[(3, 4), (1, 6)], 8), Some(19));
+        assert_eq!(solve_congruences(&[(0, 4), (1, 6)], 0), None);
+        assert_eq!(solve_congruences(&[], 1), Some(1));
+    }
+}
@@ -1,35 +1,54 @@
-use std::{fs, str::FromStr};
+use std::{
+    fmt::{self, Display},
+    str::FromStr,
+};
 
-fn main() {
-    let input_path = "input/day_9.txt";
-    let report_str = fs::read_to_string(input_path).unwrap();
-    let report = report_str.parse::<OasisReport>().unwrap();
+use crate::{
+    error::{Line, ParseError},
+    Solution,
+};
 
-    let result: i32 = report.sum_of_next_values();
-    println!("The sum of all next predictions is: {}", result);
+const DAY: u8 = 9;
 
-    let result: i32 = report.sum_of_previous_values();
-    println!("The sum of all previous predictions is: {}", result)
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input<'a> = OasisReport;
+    type Output = i32;
+
+    const HAS_PART_TWO: bool = true;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        input.parse::<OasisReport>()
+    }
+
+    fn part_one(report: &Self::Input<'_>) -> Self::Output {
+        report.sum_of_next_values()
+    }
+
+    fn part_two(report: &Self::Input<'_>) -> Option<Self::Output> {
+        Some(report.sum_of_previous_values())
+    }
 }
 
 #[derive(Debug, PartialEq)]
-struct Sequence(Vec<i32>);
+pub struct Sequence(pub Vec<i32>);
 
 impl Sequence {
-    fn new(numbers: Vec<i32>) -> Self {
+    pub fn new(numbers: Vec<i32>) -> Self {
         Sequence(numbers)
     }
 
-    fn to_difference_sequence(&self) -> Self {
+    pub fn to_difference_sequence(&self) -> Self {
         let numbers = self.0.windows(2).map(|nums| nums[1] - nums[0]).collect();
         Sequence::new(numbers)
     }
 
-    fn is_null_sequence(&self) -> bool {
+    pub fn is_null_sequence(&self) -> bool {
         self.0.iter().all(|&num| num == 0)
     }
 
-    fn predict_next_value(&self) -> i32 {
+    pub fn predict_next_value(&self) -> i32 {
         if self.is_null_sequence() {
             0
         } else {
@@ -37,7 +56,7 @@ impl Sequence {
         }
     }
 
-    fn predict_previous_value(&self) -> i32 {
+    pub fn predict_previous_value(&self) -> i32 {
         if self.is_null_sequence() {
             0
         } else {
@@ -46,46 +65,61 @@ impl Sequence {
     }
 }
 
-#[derive(Debug)]
-struct ParseSequenceError;
+impl Display for Sequence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let numbers: Vec<String> = self.0.iter().map(|n| n.to_string()).collect();
+        write!(f, "{}", numbers.join(" "))
+    }
+}
 
 impl FromStr for Sequence {
-    type Err = ParseSequenceError;
+    type Err = ParseError;
 
     fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let line = Line::new(DAY, 0, string);
         let numbers = string
             .split_ascii_whitespace()
-            .map(|s| s.parse().unwrap())
-            .collect();
+            .map(|s| line.parse_number(s))
+            .collect::<Result<_, _>>()?;
         Ok(Sequence::new(numbers))
     }
 }
 
-#[derive(Debug)]
-struct OasisReport(Vec<Sequence>);
+#[derive(Debug, PartialEq)]
+pub struct OasisReport(pub Vec<Sequence>);
 
 impl OasisReport {
-    fn new(sequences: Vec<Sequence>) -> Self {
+    pub fn new(sequences: Vec<Sequence>) -> Self {
         Self(sequences)
     }
 
-    fn sum_of_next_values(&self) -> i32 {
+    pub fn sum_of_next_values(&self) -> i32 {
         self.0.iter().map(|s| s.predict_next_value()).sum()
     }
 
-    fn sum_of_previous_values(&self) -> i32 {
+    pub fn sum_of_previous_values(&self) -> i32 {
         self.0.iter().map(|s| s.predict_previous_value()).sum()
     }
 }
 
-#[derive(Debug)]
-struct ParseOasisReportError;
+impl Display for OasisReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for sequence in &self.0 {
+            writeln!(f, "{}", sequence)?;
+        }
+        Ok(())
+    }
+}
 
 impl FromStr for OasisReport {
-    type Err = ParseOasisReportError;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let sequences = s.lines().map(|l| l.parse::<Sequence>().unwrap()).collect();
+        let sequences = s
+            .lines()
+            .enumerate()
+            .map(|(index, l)| l.parse::<Sequence>().map_err(|err| err.at_line(index + 1)))
+            .collect::<Result<_, _>>()?;
         Ok(OasisReport::new(sequences))
     }
 }
@@ -94,7 +128,7 @@ impl FromStr for OasisReport {
 mod test {
     use std::str::FromStr;
 
-    use crate::{OasisReport, Sequence};
+    use super::{OasisReport, Sequence};
 
     #[test]
     fn it_predicts_the_next_value() {
@@ -140,6 +174,19 @@ mod test {
             Sequence::new(vec![-6, -10, -14, -18, -22, -26]),
         )
     }
+    #[test]
+    fn it_reports_invalid_numbers() {
+        let report_str = "\
+0 3 6 9 12 15
+1 3 6 1O 15 21";
+
+        let result = report_str.parse::<OasisReport>().unwrap_err();
+        assert_eq!(
+            result.to_string(),
+            "day 9, line 2, column 7: expected a number, found `1O`"
+        );
+    }
+
     #[test]
     fn it_solves_next_and_prev() {
         let report_str = "\
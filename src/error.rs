@@ -0,0 +1,141 @@
+use std::{
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+/// Where and why a day's input could not be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: u8,
+    /// 1-based line number in the input.
+    pub line: usize,
+    /// 1-based column, counted in characters, of the offending text.
+    pub column: usize,
+    pub text: String,
+    pub reason: String,
+}
+
+impl ParseError {
+    pub fn new(
+        day: u8,
+        line: usize,
+        column: usize,
+        text: impl Into<String>,
+        reason: impl Into<String>,
+    ) -> Self {
+        ParseError {
+            day,
+            line,
+            column,
+            text: text.into(),
+            reason: reason.into(),
+        }
+    }
+
+    /// Moves an error raised while parsing a single line to that line's position in the input.
+    pub fn at_line(self, line: usize) -> Self {
+        ParseError { line, ..self }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "day {}, line {}, column {}: {}, found `{}`",
+            self.day, self.line, self.column, self.reason, self.text
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Why a day's part could not be solved.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SolveError {
+    Parse(ParseError),
+    /// The input is valid, but the part has no answer for it.
+    NoAnswer(String),
+}
+
+impl From<ParseError> for SolveError {
+    fn from(err: ParseError) -> Self {
+        SolveError::Parse(err)
+    }
+}
+
+impl Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SolveError::Parse(err) => write!(f, "invalid input: {}", err),
+            SolveError::NoAnswer(reason) => write!(f, "no answer: {}", reason),
+        }
+    }
+}
+
+impl std::error::Error for SolveError {}
+
+/// A line of a day's input, used to locate the fragments that fail to parse.
+pub(crate) struct Line<'a> {
+    day: u8,
+    number: usize,
+    text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    /// `index` is the 0-based index given by `lines().enumerate()`.
+    pub(crate) fn new(day: u8, index: usize, text: &'a str) -> Self {
+        Line {
+            day,
+            number: index + 1,
+            text,
+        }
+    }
+
+    /// `fragment` must be a slice of the line, its column is found from its address.
+    pub(crate) fn error(&self, fragment: &str, reason: impl Into<String>) -> ParseError {
+        let offset = (fragment.as_ptr() as usize)
+            .checked_sub(self.text.as_ptr() as usize)
+            .filter(|&offset| offset <= self.text.len())
+            .unwrap_or(0);
+        let column = self.text[..offset].chars().count() + 1;
+
+        ParseError::new(self.day, self.number, column, fragment, reason)
+    }
+
+    pub(crate) fn parse_number<T: FromStr>(&self, token: &str) -> Result<T, ParseError> {
+        token
+            .parse()
+            .map_err(|_| self.error(token, "expected a number"))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn it_locates_a_fragment_in_its_line() {
+        let text = "Game 1: 3 blue, 4 réd";
+        let line = Line::new(2, 4, text);
+        let fragment = text.split(", ").last().unwrap();
+
+        assert_eq!(
+            line.error(fragment, "expected a cube colour"),
+            ParseError::new(2, 5, 17, "4 réd", "expected a cube colour")
+        );
+    }
+
+    #[test]
+    fn it_parses_numbers_or_fails_with_the_token() {
+        let text = "0 3 x 9";
+        let line = Line::new(9, 0, text);
+        let tokens: Vec<&str> = text.split_whitespace().collect();
+
+        assert_eq!(line.parse_number::<i32>(tokens[1]), Ok(3));
+        assert_eq!(
+            line.parse_number::<i32>(tokens[2]).unwrap_err().to_string(),
+            "day 9, line 1, column 5: expected a number, found `x`"
+        );
+    }
+}
@@ -0,0 +1,453 @@
+use std::ops::RangeInclusive;
+
+use num::Integer;
+
+use crate::day05::CATEGORIES;
+
+/// A small seeded random number generator (SplitMix64), so generated inputs are reproducible.
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `0..bound`, `bound` must not be 0.
+    pub fn below(&mut self, bound: u64) -> u64 {
+        ((self.next_u64() as u128 * bound as u128) >> 64) as u64
+    }
+
+    pub fn range(&mut self, range: RangeInclusive<u64>) -> u64 {
+        range.start() + self.below(range.end() - range.start() + 1)
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len() as u64) as usize]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i as u64 + 1) as usize);
+        }
+    }
+}
+
+/// Generates an input of the given day's format, `size` being its number of records.
+pub fn input(day: u8, rng: &mut Rng, size: usize) -> Option<String> {
+    match day {
+        1 => Some(calibration_document(rng, size)),
+        2 => Some(game_records(rng, size)),
+        3 => Some(engine_schematic(rng, size)),
+        4 => Some(scratchcards(rng, size)),
+        5 => Some(almanac(rng, size)),
+        6 => Some(race_sheet(rng, size)),
+        7 => Some(camel_cards(rng, size)),
+        8 => Some(desert_network(rng, size, 6)),
+        9 => Some(oasis_report(rng, size)),
+        _ => None,
+    }
+}
+
+const DIGIT_WORDS: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+/// Lines of letters, digits and spelled out digits, each holding at least one digit.
+pub fn calibration_document(rng: &mut Rng, lines: usize) -> String {
+    (0..lines)
+        .map(|_| {
+            let mut pieces: Vec<String> = (0..rng.range(1..=8))
+                .map(|_| match rng.below(3) {
+                    0 => (0..rng.range(1..=4))
+                        .map(|_| (b'a' + rng.below(26) as u8) as char)
+                        .collect(),
+                    1 => rng.range(1..=9).to_string(),
+                    _ => rng.choose(&DIGIT_WORDS).to_string(),
+                })
+                .collect();
+            if !pieces
+                .iter()
+                .any(|p| p.starts_with(|c: char| c.is_ascii_digit()))
+            {
+                let position = rng.below(pieces.len() as u64 + 1) as usize;
+                pieces.insert(position, rng.range(1..=9).to_string());
+            }
+            pieces.concat() + "\n"
+        })
+        .collect()
+}
+
+const CUBE_COLORS: [&str; 3] = ["red", "green", "blue"];
+
+pub fn game_records(rng: &mut Rng, games: usize) -> String {
+    (1..=games)
+        .map(|id| {
+            let reveals: Vec<String> = (0..rng.range(1..=6))
+                .map(|_| {
+                    let mut colors = CUBE_COLORS;
+                    rng.shuffle(&mut colors);
+                    colors[..rng.range(1..=3) as usize]
+                        .iter()
+                        .map(|color| format!("{} {}", rng.range(1..=20), color))
+                        .collect::<Vec<String>>()
+                        .join(", ")
+                })
+                .collect();
+            format!("Game {}: {}\n", id, reveals.join("; "))
+        })
+        .collect()
+}
+
+const SYMBOLS: [char; 10] = ['*', '#', '+', '$', '/', '@', '=', '%', '&', '-'];
+
+/// A `size` by `size` grid of part numbers and symbols.
+pub fn engine_schematic(rng: &mut Rng, size: usize) -> String {
+    let mut schema = String::with_capacity((size + 1) * size);
+    for _ in 0..size {
+        let mut col = 0;
+        let mut after_number = false;
+        while col < size {
+            let digits = rng.range(1..=3) as usize;
+            match rng.below(12) {
+                0 | 1 if !after_number && col + digits <= size => {
+                    let number =
+                        rng.range(10_u64.pow(digits as u32 - 1)..=10_u64.pow(digits as u32) - 1);
+                    schema.push_str(&number.to_string());
+                    col += digits;
+                    after_number = true;
+                    continue;
+                }
+                2 => schema.push(*rng.choose(&SYMBOLS)),
+                _ => schema.push('.'),
+            }
+            col += 1;
+            after_number = false;
+        }
+        schema.push('\n');
+    }
+    schema
+}
+
+/// Scratchcards with 10 winning and 25 chosen numbers. A card never wins copies past the
+/// end of the table.
+pub fn scratchcards(rng: &mut Rng, cards: usize) -> String {
+    (1..=cards)
+        .map(|id| {
+            let mut numbers: Vec<u64> = (1..=99).collect();
+            rng.shuffle(&mut numbers);
+            let (winning, others) = numbers.split_at(10);
+
+            let nb_matches = rng.range(0..=(cards - id).min(10) as u64) as usize;
+            let mut chosen: Vec<u64> = winning[..nb_matches]
+                .iter()
+                .chain(&others[..25 - nb_matches])
+                .copied()
+                .collect();
+            rng.shuffle(&mut chosen);
+
+            let format_numbers = |numbers: &[u64]| {
+                numbers
+                    .iter()
+                    .map(|n| format!("{:>2}", n))
+                    .collect::<Vec<String>>()
+                    .join(" ")
+            };
+            format!(
+                "Card {:>3}: {} | {}\n",
+                id,
+                format_numbers(winning),
+                format_numbers(&chosen)
+            )
+        })
+        .collect()
+}
+
+const ALMANAC_LIMIT: u64 = 1 << 32;
+
+/// `size` seed ranges, and `size` non-overlapping ranges in each of the seven maps.
+pub fn almanac(rng: &mut Rng, size: usize) -> String {
+    let seeds: Vec<String> = (0..size)
+        .map(|_| {
+            let length = rng.range(1..=1_000_000);
+            format!("{} {}", rng.below(ALMANAC_LIMIT - length), length)
+        })
+        .collect();
+    let mut almanac = format!("seeds: {}\n", seeds.join(" "));
+
+    for categories in CATEGORIES.windows(2) {
+        let mut bounds: Vec<u64> = (0..2 * size).map(|_| rng.below(ALMANAC_LIMIT)).collect();
+        bounds.sort();
+        bounds.dedup();
+        let mut range_maps: Vec<String> = bounds
+            .chunks_exact(2)
+            .map(|bounds| {
+                let length = bounds[1] - bounds[0];
+                let destination = rng.below(ALMANAC_LIMIT - length);
+                format!("{} {} {}", destination, bounds[0], length)
+            })
+            .collect();
+        rng.shuffle(&mut range_maps);
+
+        almanac.push_str(&format!(
+            "\n{}-to-{} map:\n{}\n",
+            categories[0],
+            categories[1],
+            range_maps.join("\n")
+        ));
+    }
+    almanac
+}
+
+/// Races that can all be won.
+pub fn race_sheet(rng: &mut Rng, races: usize) -> String {
+    let (times, distances): (Vec<String>, Vec<String>) = (0..races)
+        .map(|_| {
+            let time = rng.range(7..=100);
+            let best_distance = (time / 2) * (time - time / 2);
+            let record = rng.below(best_distance);
+            (format!("{:>5}", time), format!("{:>5}", record))
+        })
+        .unzip();
+    format!(
+        "Time:    {}\nDistance:{}\n",
+        times.concat(),
+        distances.concat()
+    )
+}
+
+const CARD_LABELS: [char; 13] = [
+    'A', 'K', 'Q', 'J', 'T', '9', '8', '7', '6', '5', '4', '3', '2',
+];
+
+pub fn camel_cards(rng: &mut Rng, hands: usize) -> String {
+    (0..hands)
+        .map(|_| {
+            let cards: String = (0..5).map(|_| *rng.choose(&CARD_LABELS)).collect();
+            format!("{} {}\n", cards, rng.range(1..=1000))
+        })
+        .collect()
+}
+
+const CYCLE_FACTORS: [u64; 10] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29];
+
+const NAME_CHARS: &[u8; 36] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// A network walked by `ghosts` ghosts, `AAA` being the first one, over `directions` directions.
+/// Each ghost loops on a perfect cycle whose only exit is reached after a distinct prime number
+/// of passes over the directions, so the exit is found in `directions * 2` steps and the ghosts
+/// all exit together after `directions` times the product of the first `ghosts` primes.
+pub fn desert_network(rng: &mut Rng, directions: usize, ghosts: usize) -> String {
+    assert!(directions > 0, "a network needs at least one direction");
+    assert!(
+        (1..=CYCLE_FACTORS.len()).contains(&ghosts),
+        "between 1 and {} ghosts are supported",
+        CYCLE_FACTORS.len()
+    );
+
+    let directions: Vec<char> = (0..directions)
+        .map(|_| if rng.below(2) == 0 { 'L' } else { 'R' })
+        .collect();
+    let mut names = (0..).map(|n: usize| {
+        // The last character is never an `A` or a `Z`, these are kept for start and end nodes.
+        let last = NAME_CHARS
+            .iter()
+            .filter(|&&c| c != b'A' && c != b'Z')
+            .nth(n % 34)
+            .unwrap();
+        // Written in base 36 over at least two characters, growing as the network does.
+        let mut n = n / 34;
+        let mut name = vec![*last];
+        while name.len() < 3 || n > 0 {
+            name.push(NAME_CHARS[n % 36]);
+            n /= 36;
+        }
+        name.iter().rev().map(|&c| c as char).collect::<String>()
+    });
+
+    let mut lines = Vec::new();
+    for (ghost, factor) in CYCLE_FACTORS[..ghosts].iter().enumerate() {
+        let (start, exit) = match ghost {
+            0 => ("AAA".to_string(), "ZZZ".to_string()),
+            _ => (format!("{:02}A", ghost), format!("{:02}Z", ghost)),
+        };
+        let cycle_length = *factor as usize * directions.len();
+        let mut path: Vec<String> = vec![start];
+        path.extend(names.by_ref().take(cycle_length - 1));
+        path.push(exit);
+
+        let mut edges: Vec<(String, String)> = (0..cycle_length)
+            .map(|step| {
+                let next = path[step + 1].clone();
+                // Never taken on the walk, it only needs to lead somewhere.
+                let decoy = rng.choose(&path[..cycle_length]).clone();
+                match directions[step % directions.len()] {
+                    'L' => (next, decoy),
+                    _ => (decoy, next),
+                }
+            })
+            .collect();
+        // The exit leads back where the start does, closing the cycle.
+        edges.push(edges[0].clone());
+
+        lines.extend(
+            path.iter()
+                .zip(edges)
+                .map(|(node, (left, right))| format!("{} = ({}, {})", node, left, right)),
+        );
+    }
+    rng.shuffle(&mut lines);
+
+    format!(
+        "{}\n\n{}\n",
+        directions.iter().collect::<String>(),
+        lines.join("\n")
+    )
+}
+
+/// The steps the ghosts of `desert_network` need to all exit together.
+pub fn desert_network_ghostly_exit(directions: usize, ghosts: usize) -> usize {
+    CYCLE_FACTORS[..ghosts]
+        .iter()
+        .fold(directions, |acc, &factor| {
+            acc.lcm(&(factor as usize * directions))
+        })
+}
+
+/// Sequences of 21 values of polynomials of degree at most 4, so that differences end up null.
+pub fn oasis_report(rng: &mut Rng, sequences: usize) -> String {
+    (0..sequences)
+        .map(|_| {
+            let coefficients: Vec<i64> = (0..=rng.below(5))
+                .map(|_| rng.range(0..=6) as i64 - 3)
+                .collect();
+            let values: Vec<String> = (0..21_i64)
+                .map(|x| {
+                    coefficients
+                        .iter()
+                        .rev()
+                        .fold(0, |acc, coefficient| acc * x + coefficient)
+                        .to_string()
+                })
+                .collect();
+            values.join(" ") + "\n"
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        day01::Day01,
+        day02::Day02,
+        day03::Day03,
+        day04::{count_copies, Day04, TooManyCopies},
+        day05::{Almanac, Day05},
+        day06::{Day06, RaceError},
+        day07::Day07,
+        day08::DesertMap,
+        day09::Day09,
+        Solution,
+    };
+
+    #[test]
+    fn it_generates_the_same_input_from_the_same_seed() {
+        assert_eq!(
+            game_records(&mut Rng::new(42), 20),
+            game_records(&mut Rng::new(42), 20)
+        );
+        assert_ne!(
+            game_records(&mut Rng::new(42), 20),
+            game_records(&mut Rng::new(43), 20)
+        );
+    }
+
+    #[test]
+    fn it_generates_inputs_that_parse() {
+        let mut rng = Rng::new(1);
+
+        assert!(Day01::parse(&calibration_document(&mut rng, 100)).is_ok());
+        assert_eq!(
+            Day02::parse(&game_records(&mut rng, 100)).unwrap().len(),
+            100
+        );
+        let (symbols, numbers) = Day03::parse(&engine_schematic(&mut rng, 50)).unwrap();
+        assert!(!symbols.is_empty() && !numbers.is_empty());
+        assert_eq!(
+            Day04::parse(&scratchcards(&mut rng, 100)).unwrap().len(),
+            100
+        );
+        let almanac_str = almanac(&mut rng, 10);
+        assert!(Almanac::parse_strict(&almanac_str).is_ok());
+        let almanac = Day05::parse(&almanac_str).unwrap();
+        assert_eq!((almanac.seeds.len(), almanac.maps.len()), (20, 7));
+        for races in [3, 10, 100] {
+            let sheet = Day06::parse(&race_sheet(&mut rng, races)).unwrap();
+            assert_eq!(sheet.0.len(), races);
+            assert_eq!(Day06::part_two(&sheet).unwrap().is_ok(), races == 3);
+        }
+        let sheet = Day06::parse(&race_sheet(&mut rng, 100)).unwrap();
+        assert_eq!(Day06::part_one(&sheet), Err(RaceError::TooManyWays));
+        assert!(matches!(
+            Day06::part_two(&sheet),
+            Some(Err(RaceError::Unreadable(_)))
+        ));
+        assert_eq!(
+            Day07::parse(&camel_cards(&mut rng, 100)).unwrap().len(),
+            100
+        );
+        assert_eq!(
+            Day09::parse(&oasis_report(&mut rng, 100)).unwrap().0.len(),
+            100
+        );
+    }
+
+    #[test]
+    fn it_generates_scratchcards_winning_too_many_copies() {
+        let cards = Day04::parse(&scratchcards(&mut Rng::new(1), 100)).unwrap();
+
+        assert!(Day04::part_one(&cards).is_ok());
+        assert_eq!(Day04::part_two(&cards), Some(Err(TooManyCopies)));
+
+        let cards = Day04::parse(&scratchcards(&mut Rng::new(1), 20)).unwrap();
+        assert_eq!(
+            Day04::part_two(&cards),
+            Some(Ok(count_copies(&cards).unwrap().iter().sum()))
+        );
+    }
+
+    #[test]
+    fn it_generates_digits_on_every_calibration_line() {
+        let document = calibration_document(&mut Rng::new(2), 200);
+        assert!(document
+            .lines()
+            .all(|line| line.chars().any(|c| c.is_ascii_digit())));
+    }
+
+    #[test]
+    fn it_generates_desert_networks_with_known_exits() {
+        let network = desert_network(&mut Rng::new(4), 17, 4);
+        let desert_map = DesertMap::from_str(&network).unwrap();
+
+        assert_eq!(desert_map.find_the_exit(), Ok(2 * 17));
+        assert_eq!(
+            desert_map.find_the_exit_the_ghostly_way(),
+            Ok(desert_network_ghostly_exit(17, 4))
+        );
+        assert_eq!(desert_network_ghostly_exit(17, 4), 17 * 2 * 3 * 5 * 7);
+
+        // Too many nodes for 3 characters names.
+        let network = desert_network(&mut Rng::new(4), 1100, 6);
+        let desert_map = DesertMap::from_str(&network).unwrap();
+        assert!(desert_map.network.keys().any(|node| node.len() > 3));
+        assert_eq!(desert_map.find_the_exit(), Ok(2 * 1100));
+    }
+}
@@ -0,0 +1,130 @@
+use std::{
+    env,
+    ffi::OsString,
+    fmt::{self, Display},
+    fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+/// Directory holding the `day_<N>.txt` inputs, overriding the default `input/`.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+const DEFAULT_INPUT_DIR: &str = "input";
+
+#[derive(Debug, PartialEq)]
+pub enum InputSource {
+    Stdin,
+    File(PathBuf),
+}
+
+impl InputSource {
+    /// Picks where a day's input comes from: an explicit path (`-` being stdin),
+    /// then the `AOC_INPUT_DIR` directory, then the default `input/` directory.
+    pub fn resolve(day: u8, path: Option<&Path>) -> Self {
+        Self::resolve_with_dir(day, path, env::var_os(INPUT_DIR_VAR))
+    }
+
+    fn resolve_with_dir(day: u8, path: Option<&Path>, input_dir: Option<OsString>) -> Self {
+        match path {
+            Some(path) if path == Path::new("-") => InputSource::Stdin,
+            Some(path) => InputSource::File(path.to_path_buf()),
+            None => {
+                let dir = input_dir
+                    .map(PathBuf::from)
+                    .unwrap_or_else(|| PathBuf::from(DEFAULT_INPUT_DIR));
+                InputSource::File(dir.join(format!("day_{}.txt", day)))
+            }
+        }
+    }
+
+    pub fn read(&self) -> Result<String, InputError> {
+        let result = match self {
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input).map(|_| input)
+            }
+            InputSource::File(path) => fs::read_to_string(path),
+        };
+
+        result.map_err(|error| InputError {
+            source: self.to_string(),
+            error,
+        })
+    }
+}
+
+impl Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputSource::Stdin => write!(f, "stdin"),
+            InputSource::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct InputError {
+    source: String,
+    error: io::Error,
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.error.kind() {
+            io::ErrorKind::NotFound => write!(
+                f,
+                "no input found, expected a file at {} (pass an input path or set {})",
+                self.source, INPUT_DIR_VAR
+            ),
+            _ => write!(f, "cannot read input from {}: {}", self.source, self.error),
+        }
+    }
+}
+
+impl std::error::Error for InputError {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn it_resolves_the_default_input() {
+        assert_eq!(
+            InputSource::resolve_with_dir(5, None, None),
+            InputSource::File(PathBuf::from("input/day_5.txt"))
+        );
+    }
+
+    #[test]
+    fn it_resolves_the_input_dir() {
+        assert_eq!(
+            InputSource::resolve_with_dir(5, None, Some(OsString::from("/tmp/aoc"))),
+            InputSource::File(PathBuf::from("/tmp/aoc/day_5.txt"))
+        );
+    }
+
+    #[test]
+    fn it_prefers_an_explicit_path() {
+        let path = Path::new("my_input.txt");
+        assert_eq!(
+            InputSource::resolve_with_dir(5, Some(path), Some(OsString::from("/tmp/aoc"))),
+            InputSource::File(PathBuf::from("my_input.txt"))
+        );
+        assert_eq!(
+            InputSource::resolve_with_dir(5, Some(Path::new("-")), None),
+            InputSource::Stdin
+        );
+    }
+
+    #[test]
+    fn it_explains_where_the_input_was_expected() {
+        let source = InputSource::File(PathBuf::from("missing/day_5.txt"));
+        let error = source.read().unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "no input found, expected a file at missing/day_5.txt (pass an input path or set AOC_INPUT_DIR)"
+        );
+    }
+}
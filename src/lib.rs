@@ -0,0 +1,190 @@
+use std::{
+    fmt::{self, Display},
+    str::FromStr,
+    time::Instant,
+};
+
+use bench::Timings;
+use error::{ParseError, SolveError};
+use report::RunRecord;
+
+pub mod answers;
+pub mod bench;
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod error;
+pub mod generate;
+pub mod input;
+pub mod report;
+
+/// A day's puzzle: how to parse its input and how to solve both of its parts.
+pub trait Solution {
+    type Input<'a>;
+    type Output: Answer;
+
+    /// Whether `part_two` has been solved for this day.
+    const HAS_PART_TWO: bool;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError>;
+
+    /// Like `parse`, also refusing inputs that are valid but likely mistaken.
+    fn parse_strict(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Self::parse(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Self::Output;
+
+    /// Days for which part two has not been solved yet return `None`.
+    fn part_two(_input: &Self::Input<'_>) -> Option<Self::Output> {
+        None
+    }
+}
+
+/// What a part returns: its answer, or why the input has none.
+pub trait Answer {
+    fn answer(self) -> Result<String, String>;
+}
+
+macro_rules! impl_answer {
+    ($($number:ty),*) => {
+        $(impl Answer for $number {
+            fn answer(self) -> Result<String, String> {
+                Ok(self.to_string())
+            }
+        })*
+    };
+}
+
+impl_answer!(i32, i64, u32, u64, usize);
+
+impl<T: Answer, E: Display> Answer for Result<T, E> {
+    fn answer(self) -> Result<String, String> {
+        self.map_err(|err| err.to_string())?.answer()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => f.pad("1"),
+            Part::Two => f.pad("2"),
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(format!("a part is either 1 or 2, not {}", s)),
+        }
+    }
+}
+
+/// A type-erased handle on a day's `Solution`, so days can be picked at runtime.
+pub struct Puzzle {
+    pub day: u8,
+    pub has_part_two: bool,
+    solver: fn(&str, Part, bool) -> Result<Option<String>, SolveError>,
+    timer: fn(&str) -> Result<Timings, ParseError>,
+}
+
+impl Puzzle {
+    const fn new<S: Solution>(day: u8) -> Self {
+        Puzzle {
+            day,
+            has_part_two: S::HAS_PART_TWO,
+            solver: solve::<S>,
+            timer: bench::time::<S>,
+        }
+    }
+
+    pub fn is_implemented(&self, part: Part) -> bool {
+        match part {
+            Part::One => true,
+            Part::Two => self.has_part_two,
+        }
+    }
+
+    pub fn implemented_parts(&self) -> Vec<Part> {
+        [Part::One, Part::Two]
+            .into_iter()
+            .filter(|&part| self.is_implemented(part))
+            .collect()
+    }
+
+    /// Parses the input and solves the given part, or `None` if it is not implemented.
+    pub fn solve(&self, input: &str, part: Part) -> Result<Option<String>, SolveError> {
+        (self.solver)(input, part, false)
+    }
+
+    /// Solves the given part like `solve`, recording how long it took and which input was used.
+    /// A `strict` run parses the input with `Solution::parse_strict`.
+    pub fn run(
+        &self,
+        input: &str,
+        part: Part,
+        strict: bool,
+    ) -> Result<Option<RunRecord>, SolveError> {
+        let start = Instant::now();
+        let answer = (self.solver)(input, part, strict)?;
+        let elapsed = start.elapsed();
+
+        Ok(answer.map(|answer| RunRecord {
+            day: self.day,
+            part,
+            answer,
+            elapsed_ms: elapsed.as_secs_f64() * 1000.0,
+            input_hash: report::input_hash(input),
+        }))
+    }
+
+    /// Parses the input and solves every implemented part, timing each stage.
+    pub fn time(&self, input: &str) -> Result<Timings, ParseError> {
+        (self.timer)(input)
+    }
+}
+
+fn solve<S: Solution>(input: &str, part: Part, strict: bool) -> Result<Option<String>, SolveError> {
+    let input = match strict {
+        true => S::parse_strict(input)?,
+        false => S::parse(input)?,
+    };
+    let answer = match part {
+        Part::One => Some(S::part_one(&input).answer()),
+        Part::Two => S::part_two(&input).map(Answer::answer),
+    };
+    answer.transpose().map_err(SolveError::NoAnswer)
+}
+
+pub const PUZZLES: [Puzzle; 9] = [
+    Puzzle::new::<day01::Day01>(1),
+    Puzzle::new::<day02::Day02>(2),
+    Puzzle::new::<day03::Day03>(3),
+    Puzzle::new::<day04::Day04>(4),
+    Puzzle::new::<day05::Day05>(5),
+    Puzzle::new::<day06::Day06>(6),
+    Puzzle::new::<day07::Day07>(7),
+    Puzzle::new::<day08::Day08>(8),
+    Puzzle::new::<day09::Day09>(9),
+];
+
+pub fn puzzle(day: u8) -> Option<&'static Puzzle> {
+    PUZZLES.iter().find(|puzzle| puzzle.day == day)
+}
@@ -0,0 +1,66 @@
+use serde::{Serialize, Serializer};
+
+use crate::Part;
+
+/// The outcome of solving one part of a day, as reported to dashboards.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RunRecord {
+    pub day: u8,
+    pub part: Part,
+    pub answer: String,
+    /// Time spent parsing the input and solving the part.
+    pub elapsed_ms: f64,
+    /// Identifies the input the answer was computed from, see `input_hash`.
+    pub input_hash: String,
+}
+
+impl RunRecord {
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap()
+    }
+}
+
+impl Serialize for Part {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Part::One => serializer.serialize_u8(1),
+            Part::Two => serializer.serialize_u8(2),
+        }
+    }
+}
+
+/// A 64-bit FNV-1a hash of the input, stable across runs and builds unlike `DefaultHasher`.
+pub fn input_hash(input: &str) -> String {
+    let hash = input.bytes().fold(0xcbf29ce484222325_u64, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    });
+    format!("{:016x}", hash)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn it_hashes_inputs() {
+        assert_eq!(input_hash(""), "cbf29ce484222325");
+        assert_eq!(input_hash("a"), "af63dc4c8601ec8c");
+        assert_ne!(input_hash("32T3K 765"), input_hash("32T3K 766"));
+    }
+
+    #[test]
+    fn it_serializes_records_to_json() {
+        let record = RunRecord {
+            day: 9,
+            part: Part::Two,
+            answer: "-2".to_string(),
+            elapsed_ms: 0.5,
+            input_hash: "cbf29ce484222325".to_string(),
+        };
+
+        assert_eq!(
+            record.to_json(),
+            r#"{"day":9,"part":2,"answer":"-2","elapsed_ms":0.5,"input_hash":"cbf29ce484222325"}"#
+        );
+    }
+}
@@ -0,0 +1,568 @@
+use std::collections::{HashMap, HashSet};
+
+use proptest::prelude::*;
+
+use advent_of_code_2023::{
+    day01, day02, day03, day04,
+    day05::{Almanac, AlmanacMap, RangeMap, SeedRangeError, CATEGORIES},
+    day06::{self, Race},
+    day07::{Hand, HandType},
+    day08::DesertMap,
+    day09::{OasisReport, Sequence},
+    generate::{self, Rng},
+};
+
+const DIGIT_WORDS: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+/// The digit written at the start of `s`, if any.
+fn digit_at(s: &str, spelled: bool) -> Option<usize> {
+    if let Some(digit) = s.chars().next().and_then(|c| c.to_digit(10)) {
+        return Some(digit as usize);
+    }
+    match spelled {
+        true => DIGIT_WORDS
+            .iter()
+            .position(|word| s.starts_with(word))
+            .map(|index| index + 1),
+        false => None,
+    }
+}
+
+fn calibration_value(line: &str, spelled: bool) -> usize {
+    let digits: Vec<usize> = (0..line.len())
+        .filter_map(|i| digit_at(&line[i..], spelled))
+        .collect();
+    match (digits.first(), digits.last()) {
+        (Some(first), Some(last)) => first * 10 + last,
+        _ => 0,
+    }
+}
+
+fn calibration_line() -> impl Strategy<Value = String> {
+    prop::collection::vec(
+        prop_oneof![
+            "[a-z]{1,3}",
+            "[0-9]",
+            prop::sample::select(DIGIT_WORDS.to_vec()).prop_map(String::from),
+        ],
+        0..8,
+    )
+    .prop_map(|chunks| chunks.concat())
+}
+
+proptest! {
+    #[test]
+    fn day01_matches_a_character_by_character_scan(
+        lines in prop::collection::vec(calibration_line(), 1..10)
+    ) {
+        let calibration = lines.join("\n");
+
+        prop_assert_eq!(
+            day01::process_digits(&calibration),
+            lines.iter().map(|l| calibration_value(l, false)).sum::<usize>()
+        );
+        prop_assert_eq!(
+            day01::process_digits_and_strings(&calibration),
+            lines.iter().map(|l| calibration_value(l, true)).sum::<usize>()
+        );
+    }
+
+    #[test]
+    fn day02_round_trips_games(
+        games in prop::collection::vec((1..1000_usize, 0..100_usize, 0..100_usize, 0..100_usize), 1..10)
+    ) {
+        let games: Vec<day02::Game> = games
+            .into_iter()
+            .map(|(id, max_red, max_green, max_blue)| day02::Game { id, max_red, max_green, max_blue })
+            .collect();
+        let records: Vec<String> = games.iter().map(|g| g.to_string()).collect();
+
+        prop_assert_eq!(day02::parse_game_records(&records.join("\n")).unwrap(), games);
+    }
+}
+
+fn engine_schematic() -> impl Strategy<Value = String> {
+    (1..12_usize, 1..12_usize).prop_flat_map(|(rows, cols)| {
+        prop::collection::vec(
+            prop::collection::vec(
+                prop::sample::select(".....0123456789*#+".as_bytes().to_vec()),
+                cols,
+            ),
+            rows,
+        )
+        .prop_map(|grid| {
+            grid.into_iter()
+                .map(|row| String::from_utf8(row).unwrap())
+                .collect::<Vec<_>>()
+                .join("\n")
+        })
+    })
+}
+
+/// For every symbol, the numbers with a digit in one of its 8 neighbouring cells.
+fn adjacent_numbers(schema: &str) -> Vec<(char, Vec<usize>)> {
+    let grid: Vec<&[u8]> = schema.lines().map(str::as_bytes).collect();
+    let mut numbers = Vec::new();
+    for (row, line) in grid.iter().enumerate() {
+        let mut col = 0;
+        while col < line.len() {
+            if line[col].is_ascii_digit() {
+                let start = col;
+                while col < line.len() && line[col].is_ascii_digit() {
+                    col += 1;
+                }
+                let value = std::str::from_utf8(&line[start..col])
+                    .unwrap()
+                    .parse()
+                    .unwrap();
+                numbers.push((row, start..col, value));
+            } else {
+                col += 1;
+            }
+        }
+    }
+
+    let mut symbols = Vec::new();
+    for (row, line) in grid.iter().enumerate() {
+        for (col, &cell) in line.iter().enumerate() {
+            if cell != b'.' && !cell.is_ascii_digit() {
+                let adjacent = numbers
+                    .iter()
+                    .filter(|(r, cols, _)| {
+                        r.abs_diff(row) <= 1 && cols.clone().any(|c| c.abs_diff(col) <= 1)
+                    })
+                    .map(|(_, _, value)| *value)
+                    .collect();
+                symbols.push((cell as char, adjacent));
+            }
+        }
+    }
+    symbols
+}
+
+proptest! {
+    #[test]
+    fn day03_matches_a_neighbourhood_scan(schema in engine_schematic()) {
+        let engine_schematic = day03::parse_engine_schematics(&schema).unwrap();
+        let symbols = adjacent_numbers(&schema);
+
+        prop_assert_eq!(
+            day03::sum_part_numbers(&engine_schematic),
+            symbols.iter().flat_map(|(_, numbers)| numbers).sum::<usize>()
+        );
+        prop_assert_eq!(
+            day03::sum_gear_ratios(&engine_schematic),
+            symbols
+                .iter()
+                .filter(|(symbol, numbers)| *symbol == '*' && numbers.len() == 2)
+                .map(|(_, numbers)| numbers[0] * numbers[1])
+                .sum::<usize>()
+        );
+    }
+
+    #[test]
+    fn day03_round_trips_schematics(schema in engine_schematic()) {
+        let engine_schematic = day03::parse_engine_schematics(&schema).unwrap();
+        let rendered = day03::render_engine_schematic(&engine_schematic);
+
+        prop_assert_eq!(day03::parse_engine_schematics(&rendered).unwrap(), engine_schematic);
+    }
+
+    #[test]
+    fn day04_round_trips_cards(
+        cards in prop::collection::vec(
+            (prop::collection::vec(0..100_usize, 1..10), prop::collection::vec(0..100_usize, 1..25)),
+            1..10,
+        )
+    ) {
+        let cards: Vec<day04::Card> = cards
+            .into_iter()
+            .map(|(winning, chosen)| day04::Card::new(winning, chosen))
+            .collect();
+        let lines: Vec<String> = cards.iter().map(|c| c.to_string()).collect();
+
+        prop_assert_eq!(day04::parse_cards(&lines.join("\n")).unwrap(), cards);
+    }
+}
+
+/// Small enough for random values to often fall in the random ranges.
+const ALMANAC_SPAN: usize = 1 << 20;
+
+fn almanac_map() -> impl Strategy<Value = AlmanacMap> {
+    prop::collection::vec((0..ALMANAC_SPAN, 0..ALMANAC_SPAN, 1..1_usize << 16), 1..6).prop_map(
+        |ranges| {
+            AlmanacMap::new(
+                "seed",
+                "soil",
+                ranges
+                    .into_iter()
+                    .map(|(destination, source, length)| RangeMap::new(destination, source, length))
+                    .collect(),
+            )
+        },
+    )
+}
+
+/// The seven maps of the chain from seeds to locations.
+fn almanac_maps() -> impl Strategy<Value = Vec<AlmanacMap>> {
+    prop::collection::vec(almanac_map(), 7).prop_map(|maps| {
+        maps.into_iter()
+            .zip(CATEGORIES.windows(2))
+            .map(|(map, categories)| AlmanacMap::new(categories[0], categories[1], map.range_maps))
+            .collect()
+    })
+}
+
+proptest! {
+    #[test]
+    fn day05_round_trips_almanacs(
+        seeds in prop::collection::vec(0..ALMANAC_SPAN, 1..10),
+        maps in almanac_maps(),
+    ) {
+        let almanac = Almanac { seeds, maps };
+
+        prop_assert_eq!(almanac.to_string().parse::<Almanac>().unwrap(), almanac);
+    }
+
+    #[test]
+    fn day05_maps_seeds_through_the_first_matching_range(seed in 0..ALMANAC_SPAN, map in almanac_map()) {
+        let expected = map
+            .range_maps
+            .iter()
+            .find(|r| r.source_start <= seed && seed < r.source_start + r.length)
+            .map_or(seed, |r| r.destination_start + seed - r.source_start);
+
+        prop_assert_eq!(map.get_destination(seed), expected);
+    }
+
+    #[test]
+    fn day05_maps_seed_ranges_like_every_seed_in_them(
+        seeds in prop::collection::vec((0..ALMANAC_SPAN, 0..200_usize), 1..4),
+        maps in almanac_maps(),
+    ) {
+        let almanac = Almanac {
+            seeds: seeds.iter().flat_map(|&(start, length)| [start, length]).collect(),
+            maps,
+        };
+        let brute_force = seeds
+            .iter()
+            .flat_map(|&(start, length)| start..start + length)
+            .map(|seed| almanac.get_seed_location(seed).unwrap())
+            .min();
+
+        prop_assert_eq!(
+            almanac.get_closest_location_of_seed_ranges(),
+            brute_force.ok_or(SeedRangeError::NoSeeds)
+        );
+    }
+
+    #[test]
+    fn day05_finds_every_seed_of_a_location(
+        seed in 0..ALMANAC_SPAN,
+        maps in almanac_maps(),
+    ) {
+        let almanac = Almanac { seeds: vec![seed], maps };
+        let location = almanac.get_seed_location(seed).unwrap();
+        let seeds = almanac.get_location_seeds(location).unwrap();
+
+        prop_assert!(seeds.contains(&seed));
+        for seed in seeds {
+            prop_assert_eq!(almanac.get_seed_location(seed), Ok(location));
+        }
+    }
+
+    #[test]
+    fn day05_inverts_range_maps(start in 0..ALMANAC_SPAN, length in 1..50_usize, map in almanac_map()) {
+        let destinations = start..start + length;
+        let sources = map.get_source_ranges(destinations.clone());
+
+        for source in sources.iter().flat_map(|range| [range.start, range.end - 1]) {
+            prop_assert!(destinations.contains(&map.get_destination(source)));
+        }
+        prop_assert_eq!(
+            sources.iter().map(|range| range.len()).sum::<usize>(),
+            destinations.map(|destination| map.get_sources(destination).len()).sum::<usize>()
+        );
+    }
+
+    #[test]
+    fn day05_finds_every_seed_range_of_locations(
+        start in 0..ALMANAC_SPAN,
+        length in 1..30_usize,
+        maps in almanac_maps(),
+    ) {
+        let almanac = Almanac { seeds: Vec::new(), maps };
+        let locations = start..start + length;
+        let mut seed_ranges = almanac.get_seed_ranges(locations.clone()).unwrap();
+        seed_ranges.sort_by_key(|range| range.start);
+
+        prop_assert!(seed_ranges.iter().all(|range| !range.is_empty()));
+        prop_assert!(seed_ranges.windows(2).all(|pair| pair[0].end <= pair[1].start));
+        for seed in seed_ranges.iter().flat_map(|range| [range.start, range.end - 1]) {
+            prop_assert!(locations.contains(&almanac.get_seed_location(seed).unwrap()));
+        }
+        // Disjoint ranges holding every seed of the locations, and as many seeds: nothing else.
+        let seeds: Vec<usize> = locations
+            .flat_map(|location| almanac.get_location_seeds(location).unwrap())
+            .collect();
+        for seed in &seeds {
+            prop_assert!(seed_ranges.iter().any(|range| range.contains(seed)));
+        }
+        prop_assert_eq!(
+            seed_ranges.iter().map(|range| range.len()).sum::<usize>(),
+            seeds.len()
+        );
+    }
+
+    #[test]
+    fn day05_composes_the_chain_of_maps(
+        seeds in prop::collection::vec(0..ALMANAC_SPAN, 1..20),
+        maps in almanac_maps(),
+    ) {
+        let almanac = Almanac { seeds, maps };
+        let composed = almanac.compose_maps().unwrap();
+
+        prop_assert_eq!(composed.normalised(), composed.clone());
+        prop_assert_eq!(composed.to_string().lines().count(), composed.range_maps.len());
+        for &seed in &almanac.seeds {
+            prop_assert_eq!(Ok(composed.get_destination(seed)), almanac.get_seed_location(seed));
+            prop_assert_eq!(
+                almanac.convert("seed", "location", seed),
+                almanac.get_seed_location(seed)
+            );
+        }
+    }
+
+    #[test]
+    fn day06_matches_trying_every_hold_time(time in 0..200_u64, record_distance in 0..10_000_u64) {
+        let race = Race::new(time, record_distance);
+        let brute_force = (0..=time)
+            .filter(|hold| hold * (time - hold) > record_distance)
+            .count() as u64;
+
+        prop_assert_eq!(race.get_number_of_ways_to_beat_record(), brute_force);
+        prop_assert_eq!(race.get_cross_checked_number_of_ways(), Ok(brute_force));
+    }
+
+    #[test]
+    fn day06_finds_the_exact_bounds_of_huge_races(time: u64, record_distance: u64) {
+        let race = Race::new(time, record_distance);
+        let ways = race.get_number_of_ways_to_beat_record() as u128;
+        let distance = |hold: u128| hold * (time as u128 - hold);
+
+        if ways == 0 {
+            prop_assert!(distance(time as u128 / 2) <= record_distance as u128);
+        } else {
+            // The winning hold times are the `ways` ones centered on half the race.
+            let shortest_hold = (time as u128 + 1 - ways) / 2;
+            prop_assert!(distance(shortest_hold) > record_distance as u128);
+            prop_assert!(distance(shortest_hold - 1) <= record_distance as u128);
+            prop_assert_eq!(distance(shortest_hold + ways - 1), distance(shortest_hold));
+        }
+    }
+
+    #[test]
+    fn day06_parses_race_sheets(races in prop::collection::vec((0..1000_u64, 0..10_000_u64), 1..4)) {
+        let times: String = races.iter().map(|(time, _)| format!("{:>6}", time)).collect();
+        let distances: String = races.iter().map(|(_, distance)| format!("{:>6}", distance)).collect();
+        let sheet = format!("Time:   {}\nDistance:{}\n", times, distances);
+
+        let (parsed, kerned_race) = day06::parse_race_sheet(&sheet).unwrap();
+
+        prop_assert_eq!(
+            parsed,
+            races.iter().map(|&(time, distance)| Race::new(time, distance)).collect::<Vec<_>>()
+        );
+        let kerned_race = kerned_race.unwrap();
+        prop_assert_eq!(kerned_race.time, times.replace(' ', "").parse::<u64>().unwrap());
+        prop_assert_eq!(kerned_race.record_distance, distances.replace(' ', "").parse::<u64>().unwrap());
+    }
+
+    #[test]
+    fn day07_round_trips_hands(cards in "[AKQJT98765432]{5}", bid in 0..10_000_usize) {
+        let hand: Hand = format!("{} {}", cards, bid).parse().unwrap();
+
+        prop_assert_eq!(hand.to_string().parse::<Hand>().unwrap(), hand);
+    }
+
+    #[test]
+    fn day07_types_hands_by_their_card_counts(cards in prop::array::uniform5(2..15_usize)) {
+        let mut counts: HashMap<usize, usize> = HashMap::new();
+        for card in cards {
+            *counts.entry(card).or_default() += 1;
+        }
+        let mut counts: Vec<usize> = counts.into_values().collect();
+        counts.sort_unstable_by(|a, b| b.cmp(a));
+
+        let expected = match counts[..] {
+            [5] => HandType::FiveOfAKind,
+            [4, 1] => HandType::FourOfAKind,
+            [3, 2] => HandType::FullHouse,
+            [3, 1, 1] => HandType::ThreeOfAKind,
+            [2, 2, 1] => HandType::TwoPair,
+            [2, 1, 1, 1] => HandType::OnePair,
+            _ => HandType::HighCard,
+        };
+        prop_assert_eq!(HandType::from_cards(cards), expected);
+    }
+
+    #[test]
+    fn day07_plays_jokers_as_the_best_replacement(cards in "[AKQJT98765432]{5}") {
+        let hand: Hand = format!("{} 1", cards).parse().unwrap();
+        let best = (2..15_usize)
+            .map(|replacement| HandType::from_cards(hand.cards.map(|card| match card {
+                11 => replacement,
+                card => card,
+            })))
+            .max()
+            .unwrap();
+
+        prop_assert_eq!(hand.with_jokers().hand_type, best);
+    }
+
+    #[test]
+    fn day08_round_trips_networks(seed: u64, directions in 1..20_usize, ghosts in 1..4_usize) {
+        let network = generate::desert_network(&mut Rng::new(seed), directions, ghosts);
+        let desert_map = DesertMap::from_str(&network).unwrap();
+        let rendered = desert_map.to_string();
+
+        prop_assert_eq!(DesertMap::from_str(&rendered).unwrap(), desert_map);
+    }
+
+    #[test]
+    fn day08_round_trips_any_node_names(
+        names in prop::collection::hash_set("[a-zA-Z0-9_\\-é漠]{1,12}", 1..10),
+        directions in "[LR]{1,5}",
+    ) {
+        let names: Vec<String> = names.into_iter().collect();
+        let lines: Vec<String> = names
+            .iter()
+            .enumerate()
+            .map(|(index, name)| {
+                let left = &names[(index + 1) % names.len()];
+                let right = &names[(index + 2) % names.len()];
+                format!("{} = ({}, {})", name, left, right)
+            })
+            .collect();
+        let network = format!("{}\n\n{}", directions, lines.join("\n"));
+        let desert_map = DesertMap::from_str(&network).unwrap();
+
+        prop_assert_eq!(desert_map.network.len(), names.len());
+        let rendered = desert_map.to_string();
+        prop_assert_eq!(DesertMap::from_str(&rendered).unwrap(), desert_map);
+    }
+
+    #[test]
+    fn day08_finds_the_exits_of_generated_networks(
+        seed: u64,
+        directions in 1..20_usize,
+        ghosts in 1..4_usize,
+    ) {
+        let network = generate::desert_network(&mut Rng::new(seed), directions, ghosts);
+        let desert_map = DesertMap::from_str(&network).unwrap();
+
+        prop_assert_eq!(desert_map.find_the_exit(), Ok(2 * directions));
+        prop_assert_eq!(
+            desert_map.find_the_exit_the_ghostly_way(),
+            Ok(generate::desert_network_ghostly_exit(directions, ghosts))
+        );
+    }
+
+    #[test]
+    fn day08_matches_walking_every_ghost_together(
+        directions in "[LR]{1,3}",
+        nodes in prop::collection::vec((prop::sample::select(vec!['A', 'Z', 'X']), 0..8_usize, 0..8_usize), 8),
+    ) {
+        let name = |index: usize| format!("{:02}{}", index, nodes[index].0);
+        let lines: Vec<String> = (0..nodes.len())
+            .map(|index| format!("{} = ({}, {})", name(index), name(nodes[index].1), name(nodes[index].2)))
+            .collect();
+        let network = format!("{}\n\n{}", directions, lines.join("\n"));
+        let desert_map = DesertMap::from_str(&network).unwrap();
+
+        // The ghosts move together, once their joint state repeats nothing new can happen.
+        let directions: Vec<char> = directions.chars().collect();
+        let mut ghosts: Vec<usize> = (0..nodes.len()).filter(|&index| nodes[index].0 == 'A').collect();
+        let mut seen = HashSet::new();
+        let mut expected = None;
+        for step in 0.. {
+            if ghosts.is_empty() {
+                break;
+            }
+            if step > 0 && ghosts.iter().all(|&ghost| nodes[ghost].0 == 'Z') {
+                expected = Some(step);
+                break;
+            }
+            if !seen.insert((ghosts.clone(), step % directions.len())) {
+                break;
+            }
+            for ghost in ghosts.iter_mut() {
+                *ghost = match directions[step % directions.len()] {
+                    'L' => nodes[*ghost].1,
+                    _ => nodes[*ghost].2,
+                };
+            }
+        }
+
+        prop_assert_eq!(desert_map.find_the_exit_the_ghostly_way().ok(), expected);
+    }
+
+    #[test]
+    fn day08_finds_ghost_cycles_like_remembering_every_state(
+        directions in "[LR]{1,4}",
+        nodes in prop::collection::vec((0..12_usize, 0..12_usize), 12),
+        start in 0..12_usize,
+    ) {
+        let lines: Vec<String> = nodes
+            .iter()
+            .enumerate()
+            .map(|(index, (left, right))| format!("{:02}Z = ({:02}Z, {:02}Z)", index, left, right))
+            .collect();
+        let network = format!("{}\n\n{}", directions, lines.join("\n"));
+        let desert_map = DesertMap::from_str(&network).unwrap();
+        let start = format!("{:02}Z", start);
+
+        let mut first_visits = HashMap::new();
+        let mut expected = None;
+        for walk_step in desert_map.walk(&start) {
+            let walk_step = walk_step.unwrap();
+            let state = (walk_step.node, walk_step.direction_index);
+            if let Some(first_visit) = first_visits.insert(state, walk_step.step) {
+                expected = Some((first_visit, walk_step.step - first_visit));
+                break;
+            }
+        }
+        let (prefix, cycle) = expected.unwrap();
+
+        let ghost_cycle = desert_map.ghost_cycle(&start, &"*Z".parse().unwrap()).unwrap();
+        prop_assert_eq!((ghost_cycle.prefix, ghost_cycle.cycle), (prefix, cycle));
+        prop_assert_eq!(ghost_cycle.exits, (0..prefix + cycle).collect::<Vec<_>>());
+        prop_assert_eq!(
+            desert_map.find_an_exit(&start, &"*Z".parse().unwrap()),
+            Ok(1)
+        );
+    }
+
+    #[test]
+    fn day09_extrapolates_polynomials(
+        coefficients in prop::collection::vec(-5..=5_i32, 1..5),
+        length in 6..15_i32,
+    ) {
+        let p = |x: i32| coefficients.iter().rev().fold(0, |acc, c| acc * x + c);
+        let sequence = Sequence::new((0..length).map(p).collect());
+
+        prop_assert_eq!(sequence.predict_next_value(), p(length));
+        prop_assert_eq!(sequence.predict_previous_value(), p(-1));
+    }
+
+    #[test]
+    fn day09_round_trips_reports(
+        sequences in prop::collection::vec(prop::collection::vec(-1000..1000_i32, 1..20), 1..10)
+    ) {
+        let report = OasisReport::new(sequences.into_iter().map(Sequence::new).collect());
+
+        prop_assert_eq!(report.to_string().parse::<OasisReport>().unwrap(), report);
+    }
+}